@@ -0,0 +1,157 @@
+use polars::prelude::*;
+
+use super::error::*;
+
+/// Builds an expression rendering the values of a column as category labels.
+pub(crate) fn category_expr(name: &str) -> Expr {
+    col(name).cast(DataType::String)
+}
+
+/// The key column of the mapping frames joined by `lookup_categories`.
+pub(crate) const LOOKUP_KEY_COLUMN: &str = "__category_lookup_key";
+
+/// The value column of the mapping frames joined by `lookup_categories`.
+pub(crate) const LOOKUP_VALUE_COLUMN: &str = "__category_lookup_value";
+
+/// Counts the occurrences of every non-null category in a column.
+///
+/// Categories are returned in the natural order of the column's data type, that is
+/// numerically for integer columns and lexically for string and categorical columns, and
+/// are rendered as labels afterwards.
+pub(crate) fn category_counts(
+    df: &DataFrame,
+    name: &str,
+) -> Result<Vec<(String, u32)>, EncodingError> {
+    let key = if df.column(name)?.dtype().is_categorical() {
+        col(name).cast(DataType::String)
+    } else {
+        col(name)
+    };
+
+    let counts = df
+        .clone()
+        .lazy()
+        .select([key])
+        .drop_nulls(None)
+        .group_by([col(name)])
+        .agg([len().alias("count")])
+        .sort([name], SortMultipleOptions::default())
+        .select([category_expr(name), col("count")])
+        .collect()?;
+
+    let categories = counts.column(name)?.str()?;
+    let frequencies = counts.column("count")?.u32()?;
+
+    Ok(categories
+        .into_iter()
+        .zip(frequencies)
+        .filter_map(|(category, count)| Some((category?.to_string(), count?)))
+        .collect())
+}
+
+/// Maps `key` through a mapping frame with a left join, keeping the row order.
+///
+/// The mapping frame holds the keys in `LOOKUP_KEY_COLUMN` and the mapped values in
+/// `LOOKUP_VALUE_COLUMN`. The returned frame gains `LOOKUP_VALUE_COLUMN`, which is null
+/// for rows whose key is null or absent from the mapping.
+pub(crate) fn lookup_categories(lf: LazyFrame, key: Expr, mapping: DataFrame) -> LazyFrame {
+    let mut join_args = JoinArgs::new(JoinType::Left);
+    join_args.maintain_order = MaintainOrderJoin::Left;
+
+    lf.with_column(key.alias(LOOKUP_KEY_COLUMN))
+        .join(
+            mapping.lazy(),
+            [col(LOOKUP_KEY_COLUMN)],
+            [col(LOOKUP_KEY_COLUMN)],
+            join_args,
+        )
+        .drop([LOOKUP_KEY_COLUMN])
+}
+
+/// Returns the distinct non-null categories of a column that are not in `known`.
+pub(crate) fn unknown_categories(
+    df: &DataFrame,
+    name: &str,
+    known: &[String],
+) -> Result<Vec<String>, EncodingError> {
+    Ok(category_counts(df, name)?
+        .into_iter()
+        .map(|(category, _)| category)
+        .filter(|category| !known.contains(category))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_counts() {
+        let df = df!["size" => [Some("m"), Some("s"), None, Some("m"), Some("l")]].unwrap();
+        let counts = category_counts(&df, "size").unwrap();
+        assert_eq!(
+            counts,
+            vec![
+                ("l".to_string(), 1),
+                ("m".to_string(), 2),
+                ("s".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_category_counts_integer() {
+        let df = df!["code" => [3i64, 1, 3]].unwrap();
+        let counts = category_counts(&df, "code").unwrap();
+        assert_eq!(counts, vec![("1".to_string(), 1), ("3".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_category_counts_integer_order() {
+        let df = df!["code" => [2i64, 10, 1]].unwrap();
+        let counts = category_counts(&df, "code").unwrap();
+        assert_eq!(
+            counts,
+            vec![
+                ("1".to_string(), 1),
+                ("2".to_string(), 1),
+                ("10".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lookup_categories() {
+        let df = df!["size" => [Some("m"), None, Some("xl"), Some("s")]].unwrap();
+        let mapping = df![
+            LOOKUP_KEY_COLUMN => ["s", "m"],
+            LOOKUP_VALUE_COLUMN => [0i64, 1]
+        ]
+        .unwrap();
+
+        let looked_up = lookup_categories(df.lazy(), col("size"), mapping)
+            .collect()
+            .unwrap();
+        assert_eq!(looked_up.get_column_names(), ["size", LOOKUP_VALUE_COLUMN]);
+        assert_eq!(
+            looked_up
+                .column(LOOKUP_VALUE_COLUMN)
+                .unwrap()
+                .i64()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(1), None, None, Some(0)]
+        );
+    }
+
+    #[test]
+    fn test_unknown_categories() {
+        let df = df!["size" => ["m", "xl", "s"]].unwrap();
+        let known = vec!["m".to_string(), "s".to_string()];
+        assert_eq!(
+            unknown_categories(&df, "size", &known).unwrap(),
+            vec!["xl".to_string()]
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use polars::prelude::*;
+
+use super::categories::*;
+use super::error::*;
+use super::traits::CategoricalEncoder;
+use crate::validation::error::*;
+
+/// The encoding given to categories that were not seen during fitting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnknownCountPolicy {
+    /// Unseen categories were seen zero times.
+    Zero,
+    /// A fixed value.
+    Value(f64),
+    /// Fail with `EncodingError::UnknownCategoryError`.
+    Error,
+}
+
+/// The categories of a fitted column with the number of rows they were seen in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FittedCounts {
+    pub column: String,
+    /// Categories in their natural order with their counts.
+    pub counts: Vec<(String, u32)>,
+    /// Number of missing values.
+    pub missing: u32,
+}
+
+/// Replaces every category with the number of rows it was seen in at fit time, or with
+/// its share of the rows when normalized. Encoded columns are Float64.
+pub struct CountEncoder {
+    normalize: bool,
+    unknown_policy: UnknownCountPolicy,
+    count_missing: bool,
+    n_rows: Option<usize>,
+    counts: Option<Vec<FittedCounts>>,
+}
+
+impl Default for CountEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CountEncoder {
+    pub fn new() -> Self {
+        CountEncoder {
+            normalize: false,
+            unknown_policy: UnknownCountPolicy::Zero,
+            count_missing: false,
+            n_rows: None,
+            counts: None,
+        }
+    }
+
+    /// Divides the counts by the number of fitted rows.
+    pub fn with_normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    pub fn with_unknown_policy(mut self, unknown_policy: UnknownCountPolicy) -> Self {
+        self.unknown_policy = unknown_policy;
+        self
+    }
+
+    /// Encodes missing values with the number of missing values seen at fit time,
+    /// instead of keeping them null.
+    pub fn with_count_missing(mut self, count_missing: bool) -> Self {
+        self.count_missing = count_missing;
+        self
+    }
+
+    pub fn counts(&self) -> Option<&[FittedCounts]> {
+        self.counts.as_deref()
+    }
+
+    fn scale(&self, count: u32, n_rows: usize) -> f64 {
+        if self.normalize {
+            count as f64 / n_rows as f64
+        } else {
+            count as f64
+        }
+    }
+}
+
+impl CategoricalEncoder for CountEncoder {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), EncodingError> {
+        validate_not_empty_df(df)?;
+        validate_categorical_columns(df)?;
+
+        let counts = df
+            .get_columns()
+            .iter()
+            .map(|column| {
+                Ok(FittedCounts {
+                    column: column.name().to_string(),
+                    counts: category_counts(df, column.name())?,
+                    missing: column.null_count() as u32,
+                })
+            })
+            .collect::<Result<Vec<_>, EncodingError>>()?;
+
+        self.n_rows = Some(df.height());
+        self.counts = Some(counts);
+
+        Ok(())
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, EncodingError> {
+        let (Some(counts), Some(n_rows)) = (&self.counts, self.n_rows) else {
+            return Err(EncodingError::NotFittedError);
+        };
+
+        let fitted: Vec<&str> = counts.iter().map(|fitted| fitted.column.as_str()).collect();
+        validate_columns_present(df, &fitted)?;
+
+        if self.unknown_policy == UnknownCountPolicy::Error {
+            let mut unknown_cols: Vec<String> = Vec::new();
+            for fitted in counts {
+                let known: Vec<String> = fitted
+                    .counts
+                    .iter()
+                    .map(|(category, _)| category.clone())
+                    .collect();
+                let unknown = unknown_categories(df, &fitted.column, &known)?;
+                if !unknown.is_empty() {
+                    unknown_cols.push(format!("{} ({})", fitted.column, unknown.join(", ")));
+                }
+            }
+
+            if !unknown_cols.is_empty() {
+                return Err(EncodingError::UnknownCategoryError(unknown_cols.join(", ")));
+            }
+        }
+
+        let unknown = match self.unknown_policy {
+            UnknownCountPolicy::Value(value) => value,
+            _ => 0.0,
+        };
+
+        let mut encoded = df.clone().lazy();
+        for fitted in counts {
+            let name = fitted.column.as_str();
+            let (categories, values): (Vec<&str>, Vec<f64>) = fitted
+                .counts
+                .iter()
+                .map(|(category, count)| (category.as_str(), self.scale(*count, n_rows)))
+                .unzip();
+            let mapping = df![LOOKUP_KEY_COLUMN => categories, LOOKUP_VALUE_COLUMN => values]?;
+
+            let missing = if self.count_missing {
+                lit(self.scale(fitted.missing, n_rows))
+            } else {
+                lit(NULL).cast(DataType::Float64)
+            };
+
+            encoded = lookup_categories(encoded, category_expr(name), mapping)
+                .with_column(
+                    when(col(name).is_null())
+                        .then(missing)
+                        .otherwise(col(LOOKUP_VALUE_COLUMN).fill_null(lit(unknown)))
+                        .alias(name),
+                )
+                .drop([LOOKUP_VALUE_COLUMN]);
+        }
+
+        Ok(encoded.collect()?)
+    }
+
+    fn reset(&mut self) {
+        self.n_rows = None;
+        self.counts = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "city" => [Some("b"), Some("a"), Some("b"), None, Some("b")],
+            "code" => [1i64, 2, 2, 3, 2]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let encoder = CountEncoder::new();
+        assert!(!encoder.normalize);
+        assert!(!encoder.count_missing);
+        assert_eq!(encoder.unknown_policy, UnknownCountPolicy::Zero);
+        assert!(encoder.counts().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut encoder = CountEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        let counts = encoder.counts().unwrap();
+        assert_eq!(
+            counts[0],
+            FittedCounts {
+                column: "city".to_string(),
+                counts: vec![("a".to_string(), 1), ("b".to_string(), 3)],
+                missing: 1,
+            }
+        );
+        assert_eq!(counts[1].counts.len(), 3);
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_counts() {
+        let mut encoder = CountEncoder::new();
+        let encoded_df = encoder.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "city" => [Some(3.0f64), Some(1.0), Some(3.0), None, Some(3.0)],
+            "code" => [1.0f64, 3.0, 3.0, 1.0, 3.0]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_normalized_with_missing() {
+        let mut encoder = CountEncoder::new()
+            .with_normalize(true)
+            .with_count_missing(true);
+        let encoded_df = encoder.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "city" => [0.6f64, 0.2, 0.6, 0.2, 0.6],
+            "code" => [0.2f64, 0.6, 0.6, 0.2, 0.6]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_unknown() {
+        let mut encoder = CountEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        let df = df![
+            "city" => ["z", "a"],
+            "code" => [9i64, 2]
+        ]
+        .unwrap();
+        let expected = df![
+            "city" => [0.0f64, 1.0],
+            "code" => [0.0f64, 3.0]
+        ]
+        .unwrap();
+        assert_eq!(encoder.transform(&df).unwrap(), expected);
+
+        let mut encoder = CountEncoder::new().with_unknown_policy(UnknownCountPolicy::Value(-1.0));
+        encoder.fit(&create_df()).unwrap();
+        let encoded_df = encoder.transform(&df).unwrap();
+        assert_eq!(
+            encoded_df.column("city").unwrap().get(0).unwrap(),
+            AnyValue::Float64(-1.0)
+        );
+    }
+
+    #[test]
+    fn test_transform_unknown_error() {
+        let mut encoder = CountEncoder::new().with_unknown_policy(UnknownCountPolicy::Error);
+        encoder.fit(&create_df()).unwrap();
+
+        let df = df![
+            "city" => ["z", "a"],
+            "code" => [1i64, 2]
+        ]
+        .unwrap();
+        assert!(matches!(
+            encoder.transform(&df),
+            Err(EncodingError::UnknownCategoryError(ref cols)) if cols == "city (z)"
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let encoder = CountEncoder::new();
+        assert!(matches!(
+            encoder.transform(&create_df()),
+            Err(EncodingError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut encoder = CountEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        encoder.reset();
+        assert!(encoder.counts().is_none());
+    }
+}
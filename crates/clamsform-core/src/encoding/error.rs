@@ -0,0 +1,95 @@
+use polars::prelude::*;
+use thiserror::Error;
+
+use crate::validation::error::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum EncodingError {
+    #[error(
+        "The encoder has not been fitted. \
+        Call `fit` before attempting to transform a DataFrame."
+    )]
+    NotFittedError,
+
+    #[error(
+        "The column(s) {0} contain non-categorical data. \
+        Encoders can only be applied to string, categorical, boolean or integer columns."
+    )]
+    NonCategoricalError(String),
+
+    #[error(
+        "The column(s) {0} contain categories that were not seen during fitting. \
+        Configure an unknown category policy to ignore or group unseen categories."
+    )]
+    UnknownCategoryError(String),
+
+    #[error(
+        "The encoded column name(s) {0} would be produced more than once. \
+        Rename the columns or categories so that every `<column>_<category>` name is unique."
+    )]
+    DuplicateFeatureNamesError(String),
+
+    #[error("Invalid encoder parameter: {0}")]
+    InvalidParameterError(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
+
+/// Validates that all columns in the DataFrame hold categorical data.
+///
+/// # Arguments
+/// * `df` - The DataFrame to validate column types.
+///
+/// # Returns
+/// * `Ok(())` if all columns are string, categorical, boolean or integer columns.
+/// * `Err(EncodingError::NonCategoricalError)` with a list of columns holding other data types.
+pub fn validate_categorical_columns(df: &DataFrame) -> Result<(), EncodingError> {
+    let non_categorical_cols: Vec<String> = df
+        .get_columns()
+        .iter()
+        .filter(|col| {
+            let dtype = col.dtype();
+            !(dtype.is_string() || dtype.is_categorical() || dtype.is_bool() || dtype.is_integer())
+        })
+        .map(|col| col.name().to_string())
+        .collect();
+
+    if !non_categorical_cols.is_empty() {
+        return Err(EncodingError::NonCategoricalError(
+            non_categorical_cols.join(", "),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_categorical_columns() {
+        let invalid_df = df![
+            "label" => ["a", "b"],
+            "float" => [1.0f64, 2.0]
+        ]
+        .unwrap();
+        let result = validate_categorical_columns(&invalid_df);
+        assert!(matches!(
+            result,
+            Err(EncodingError::NonCategoricalError(ref cols)) if cols == "float"
+        ));
+
+        let valid_df = df![
+            "label" => ["a", "b"],
+            "code" => [1i64, 2],
+            "flag" => [true, false]
+        ]
+        .unwrap();
+        assert!(validate_categorical_columns(&valid_df).is_ok());
+    }
+}
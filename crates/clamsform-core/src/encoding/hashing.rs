@@ -0,0 +1,291 @@
+use polars::prelude::*;
+
+use super::categories::*;
+use super::error::*;
+use super::traits::CategoricalEncoder;
+use crate::validation::error::*;
+
+/// Computes the 32-bit x86 variant of MurmurHash3.
+///
+/// The hash only depends on the bytes and the seed, so it is stable across platforms
+/// and releases.
+pub fn murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    let mix = |mut k: u32| {
+        k = k.wrapping_mul(C1);
+        k = k.rotate_left(15);
+        k.wrapping_mul(C2)
+    };
+
+    let mut h = seed;
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        let k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        h ^= mix(k);
+        h = h.rotate_left(13);
+        h = h.wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let k = tail
+            .iter()
+            .enumerate()
+            .fold(0u32, |k, (i, &byte)| k ^ (u32::from(byte) << (8 * i)));
+        h ^= mix(k);
+    }
+
+    h ^= bytes.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+/// Hashes the values of categorical columns, and the tokens of list of string columns,
+/// into a fixed number of Float64 columns named `hash_0` to `hash_<n_features - 1>`.
+///
+/// Every value is hashed as `<column>=<value>` with `murmurhash3_32`. The hash selects the
+/// output column, and by default its sign selects whether the value adds or subtracts 1,
+/// so that collisions tend to cancel out instead of accumulating. Null values are skipped.
+///
+/// The hasher is stateless: `fit` only validates the DataFrame, and `transform` can be
+/// called without fitting.
+pub struct FeatureHasher {
+    n_features: usize,
+    alternate_sign: bool,
+    seed: u32,
+}
+
+impl Default for FeatureHasher {
+    fn default() -> Self {
+        Self::new(1 << 10)
+    }
+}
+
+impl FeatureHasher {
+    pub fn new(n_features: usize) -> Self {
+        FeatureHasher {
+            n_features,
+            alternate_sign: true,
+            seed: 0,
+        }
+    }
+
+    /// Uses the sign of the hash to alternate the sign of the values. Enabled by default.
+    pub fn with_alternate_sign(mut self, alternate_sign: bool) -> Self {
+        self.alternate_sign = alternate_sign;
+        self
+    }
+
+    /// Sets the seed of the hash function.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn n_features(&self) -> usize {
+        self.n_features
+    }
+
+    /// The names of the output columns.
+    pub fn feature_names_out(&self) -> Vec<String> {
+        (0..self.n_features).map(|i| format!("hash_{i}")).collect()
+    }
+
+    /// Returns the output column and the sign of a value of a column.
+    fn bucket(&self, column: &str, value: &str) -> (usize, f64) {
+        let hash = murmurhash3_32(format!("{column}={value}").as_bytes(), self.seed) as i32;
+        let index = hash.unsigned_abs() as usize % self.n_features;
+        let sign = if self.alternate_sign && hash < 0 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        (index, sign)
+    }
+
+    fn validate(&self, df: &DataFrame) -> Result<(), EncodingError> {
+        if self.n_features == 0 {
+            return Err(EncodingError::InvalidParameterError(
+                "n_features must be at least 1".to_string(),
+            ));
+        }
+
+        let non_categorical_cols: Vec<String> = df
+            .get_columns()
+            .iter()
+            .filter(|col| {
+                let dtype = col.dtype();
+                !(dtype.is_string()
+                    || dtype.is_categorical()
+                    || dtype.is_bool()
+                    || dtype.is_integer()
+                    || matches!(dtype, DataType::List(inner) if inner.is_string()))
+            })
+            .map(|col| col.name().to_string())
+            .collect();
+
+        if !non_categorical_cols.is_empty() {
+            return Err(EncodingError::NonCategoricalError(
+                non_categorical_cols.join(", "),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl CategoricalEncoder for FeatureHasher {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), EncodingError> {
+        validate_not_empty_df(df)?;
+        self.validate(df)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, EncodingError> {
+        self.validate(df)?;
+
+        let mut features = vec![vec![0.0f64; df.height()]; self.n_features];
+        for column in df.get_columns() {
+            let name = column.name().as_str();
+
+            if let DataType::List(_) = column.dtype() {
+                for (row, tokens) in column.list()?.into_iter().enumerate() {
+                    let Some(tokens) = tokens else { continue };
+                    for token in tokens.str()?.into_iter().flatten() {
+                        let (index, sign) = self.bucket(name, token);
+                        features[index][row] += sign;
+                    }
+                }
+            } else {
+                let values = df.clone().lazy().select([category_expr(name)]).collect()?;
+                for (row, value) in values.column(name)?.str()?.into_iter().enumerate() {
+                    let Some(value) = value else { continue };
+                    let (index, sign) = self.bucket(name, value);
+                    features[index][row] += sign;
+                }
+            }
+        }
+
+        let columns: Vec<Column> = self
+            .feature_names_out()
+            .into_iter()
+            .zip(features)
+            .map(|(name, values)| Column::new(name.into(), values))
+            .collect();
+
+        Ok(DataFrame::new(columns)?)
+    }
+
+    /// The hasher holds no fitted state.
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "color" => [Some("red"), Some("blue"), None],
+            "size" => [1i64, 2, 1]
+        ]
+        .unwrap()
+    }
+
+    // Test the hash function against reference MurmurHash3 values
+    #[test]
+    fn test_murmurhash3_32() {
+        assert_eq!(murmurhash3_32(b"", 0), 0);
+        assert_eq!(murmurhash3_32(b"", 1), 0x514e_28b7);
+        assert_eq!(murmurhash3_32(b"test", 0), 0xba6b_d213);
+        assert_eq!(murmurhash3_32(b"Hello, world!", 0), 0xc036_3e43);
+        assert_eq!(
+            murmurhash3_32(b"The quick brown fox jumps over the lazy dog", 0),
+            0x2e4f_f723
+        );
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let hasher = FeatureHasher::new(8);
+        assert_eq!(hasher.n_features(), 8);
+        assert!(hasher.alternate_sign);
+        assert_eq!(hasher.seed, 0);
+        assert_eq!(FeatureHasher::default().n_features(), 1 << 10);
+    }
+
+    #[test]
+    fn test_feature_names_out() {
+        let hasher = FeatureHasher::new(3);
+        assert_eq!(hasher.feature_names_out(), ["hash_0", "hash_1", "hash_2"]);
+    }
+
+    // Test transform method against pinned outputs
+    #[test]
+    fn test_transform_golden() {
+        let hasher = FeatureHasher::new(4);
+        let hashed_df = hasher.transform(&create_df()).unwrap();
+
+        let expected = df![
+            "hash_0" => [0.0f64, -1.0, 0.0],
+            "hash_1" => [1.0f64, 0.0, 1.0],
+            "hash_2" => [-1.0f64, 1.0, 0.0],
+            "hash_3" => [0.0f64, 0.0, 0.0]
+        ]
+        .unwrap();
+        assert_eq!(hashed_df, expected);
+    }
+
+    #[test]
+    fn test_transform_tokens_golden() {
+        let tokens = Series::new(
+            "tokens".into(),
+            [
+                Series::new("".into(), ["a", "b", "a"]),
+                Series::new("".into(), ["c"]),
+            ],
+        );
+        let df = DataFrame::new(vec![tokens.into_column()]).unwrap();
+
+        let hasher = FeatureHasher::new(4).with_alternate_sign(false);
+        let hashed_df = hasher.transform(&df).unwrap();
+
+        let expected = df![
+            "hash_0" => [1.0f64, 0.0],
+            "hash_1" => [2.0f64, 0.0],
+            "hash_2" => [0.0f64, 0.0],
+            "hash_3" => [0.0f64, 1.0]
+        ]
+        .unwrap();
+        assert_eq!(hashed_df, expected);
+    }
+
+    #[test]
+    fn test_transform_is_stateless() {
+        let mut hasher = FeatureHasher::new(16);
+        let unfitted_df = hasher.transform(&create_df()).unwrap();
+        let fitted_df = hasher.fit_transform(&create_df()).unwrap();
+        assert_eq!(unfitted_df, fitted_df);
+    }
+
+    #[test]
+    fn test_transform_invalid() {
+        let hasher = FeatureHasher::new(0);
+        assert!(matches!(
+            hasher.transform(&create_df()),
+            Err(EncodingError::InvalidParameterError(_))
+        ));
+
+        let df = df!["value" => [1.0f64, 2.0]].unwrap();
+        assert!(matches!(
+            FeatureHasher::new(4).transform(&df),
+            Err(EncodingError::NonCategoricalError(ref cols)) if cols == "value"
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+use polars::prelude::*;
+
+use super::categories::*;
+use super::error::*;
+use crate::validation::error::*;
+
+/// Encodes the class labels of a target Series as integer codes, from 0 upwards in the
+/// natural order of the labels: numerically for integer labels and lexically otherwise.
+///
+/// Missing labels stay null. `inverse_transform` maps codes, such as predicted classes, back
+/// to labels of the fitted data type.
+pub struct LabelEncoder {
+    classes: Option<Vec<String>>,
+    dtype: Option<DataType>,
+}
+
+impl Default for LabelEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LabelEncoder {
+    pub fn new() -> Self {
+        LabelEncoder {
+            classes: None,
+            dtype: None,
+        }
+    }
+
+    /// The fitted class labels. A label's code is its position.
+    pub fn classes(&self) -> Option<&[String]> {
+        self.classes.as_deref()
+    }
+
+    /// The data type of the fitted labels.
+    pub fn dtype(&self) -> Option<&DataType> {
+        self.dtype.as_ref()
+    }
+
+    fn fitted_classes(&self) -> Result<&Vec<String>, EncodingError> {
+        self.classes.as_ref().ok_or(EncodingError::NotFittedError)
+    }
+
+    /// Learns the class labels of the target.
+    pub fn fit(&mut self, y: &Series) -> Result<(), EncodingError> {
+        let df = y.clone().into_frame();
+        validate_not_empty_df(&df)?;
+        validate_categorical_columns(&df)?;
+
+        let classes = category_counts(&df, y.name())?
+            .into_iter()
+            .map(|(class, _)| class)
+            .collect();
+
+        self.classes = Some(classes);
+        self.dtype = Some(y.dtype().clone());
+
+        Ok(())
+    }
+
+    /// Replaces every label with its UInt32 code.
+    pub fn transform(&self, y: &Series) -> Result<Series, EncodingError> {
+        let classes = self.fitted_classes()?;
+        let df = y.clone().into_frame();
+        validate_categorical_columns(&df)?;
+
+        let unknown = unknown_categories(&df, y.name(), classes)?;
+        if !unknown.is_empty() {
+            return Err(EncodingError::UnknownCategoryError(format!(
+                "{} ({})",
+                y.name(),
+                unknown.join(", ")
+            )));
+        }
+
+        let mapping = df![
+            LOOKUP_KEY_COLUMN => classes,
+            LOOKUP_VALUE_COLUMN => (0..classes.len() as u32).collect::<Vec<_>>()
+        ]?;
+
+        let encoded_df = lookup_categories(df.lazy(), category_expr(y.name()), mapping)
+            .select([col(LOOKUP_VALUE_COLUMN).alias(y.name().clone())])
+            .collect()?;
+
+        Ok(encoded_df
+            .column(y.name())?
+            .as_materialized_series()
+            .clone())
+    }
+
+    /// Maps codes back to their class labels.
+    ///
+    /// Codes that do not correspond to a class become null.
+    pub fn inverse_transform(&self, y: &Series) -> Result<Series, EncodingError> {
+        let classes = self.fitted_classes()?;
+        let dtype = self.dtype.as_ref().ok_or(EncodingError::NotFittedError)?;
+
+        if !y.dtype().is_integer() {
+            return Err(EncodingError::InvalidParameterError(format!(
+                "the codes of {} must be integers, got {}",
+                y.name(),
+                y.dtype()
+            )));
+        }
+
+        let mapping = df![
+            LOOKUP_KEY_COLUMN => (0..classes.len() as i64).collect::<Vec<_>>(),
+            LOOKUP_VALUE_COLUMN => classes
+        ]?;
+
+        let decoded_df = lookup_categories(
+            y.clone().into_frame().lazy(),
+            col(y.name().clone()).cast(DataType::Int64),
+            mapping,
+        )
+        .select([col(LOOKUP_VALUE_COLUMN)
+            .cast(dtype.clone())
+            .alias(y.name().clone())])
+        .collect()?;
+
+        Ok(decoded_df
+            .column(y.name())?
+            .as_materialized_series()
+            .clone())
+    }
+
+    /// Learns the class labels and returns their codes.
+    pub fn fit_transform(&mut self, y: &Series) -> Result<Series, EncodingError> {
+        self.fit(y)?;
+        self.transform(y)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.classes = None;
+        self.dtype = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_target() -> Series {
+        Series::new(
+            "species".into(),
+            [Some("dog"), Some("cat"), None, Some("bird"), Some("cat")],
+        )
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let encoder = LabelEncoder::default();
+        assert!(encoder.classes().is_none());
+        assert!(encoder.dtype().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut encoder = LabelEncoder::new();
+        encoder.fit(&create_target()).unwrap();
+
+        assert_eq!(encoder.classes().unwrap(), ["bird", "cat", "dog"]);
+        assert_eq!(encoder.dtype(), Some(&DataType::String));
+    }
+
+    #[test]
+    fn test_fit_integer_order() {
+        let mut encoder = LabelEncoder::new();
+        let y = Series::new("grade".into(), [2i64, 10, 1]);
+        let codes = encoder.fit_transform(&y).unwrap();
+
+        assert_eq!(encoder.classes().unwrap(), ["1", "2", "10"]);
+        assert_eq!(codes, Series::new("grade".into(), [1u32, 2, 0]));
+        assert_eq!(encoder.inverse_transform(&codes).unwrap(), y);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut encoder = LabelEncoder::new();
+        let y = Series::new("price".into(), [1.5f64, 2.5]);
+        assert!(matches!(
+            encoder.fit(&y),
+            Err(EncodingError::NonCategoricalError(ref cols)) if cols == "price"
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut encoder = LabelEncoder::new();
+        let codes = encoder.fit_transform(&create_target()).unwrap();
+
+        let expected = Series::new(
+            "species".into(),
+            [Some(2u32), Some(1), None, Some(0), Some(1)],
+        );
+        assert_eq!(codes, expected);
+    }
+
+    #[test]
+    fn test_transform_unknown() {
+        let mut encoder = LabelEncoder::new();
+        encoder.fit(&create_target()).unwrap();
+
+        let y = Series::new("species".into(), ["cat", "fish"]);
+        assert!(matches!(
+            encoder.transform(&y),
+            Err(EncodingError::UnknownCategoryError(ref cols)) if cols == "species (fish)"
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let encoder = LabelEncoder::new();
+        assert!(matches!(
+            encoder.transform(&create_target()),
+            Err(EncodingError::NotFittedError)
+        ));
+    }
+
+    // Test inverse_transform method
+    #[test]
+    fn test_inverse_transform() {
+        let mut encoder = LabelEncoder::new();
+        let codes = encoder.fit_transform(&create_target()).unwrap();
+        assert_eq!(encoder.inverse_transform(&codes).unwrap(), create_target());
+
+        let predictions = Series::new("prediction".into(), [0i64, 2, 7]);
+        let expected = Series::new("prediction".into(), [Some("bird"), Some("dog"), None]);
+        assert_eq!(encoder.inverse_transform(&predictions).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_inverse_transform_integer_labels() {
+        let mut encoder = LabelEncoder::new();
+        let y = Series::new("grade".into(), [3i32, 1, 3]);
+        let codes = encoder.fit_transform(&y).unwrap();
+
+        assert_eq!(encoder.inverse_transform(&codes).unwrap(), y);
+        assert!(matches!(
+            encoder.inverse_transform(&Series::new("grade".into(), [0.5f64])),
+            Err(EncodingError::InvalidParameterError(_))
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut encoder = LabelEncoder::new();
+        encoder.fit(&create_target()).unwrap();
+
+        encoder.reset();
+        assert!(encoder.classes().is_none());
+        assert!(encoder.dtype().is_none());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod categories;
+pub mod count;
+pub mod error;
+pub mod hashing;
+pub mod label;
+pub mod one_hot;
+pub mod ordinal;
+pub mod target;
+pub mod traits;
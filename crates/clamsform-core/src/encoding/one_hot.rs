@@ -0,0 +1,475 @@
+use std::collections::HashSet;
+
+use polars::prelude::*;
+
+use super::categories::*;
+use super::error::*;
+use super::traits::CategoricalEncoder;
+use crate::validation::error::*;
+
+/// The label of the bucket collecting infrequent and, optionally, unknown categories.
+pub const OTHER_CATEGORY: &str = "other";
+
+/// Which category column, if any, is dropped to avoid collinearity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OneHotDrop {
+    None,
+    First,
+}
+
+/// How categories that were not seen during fitting are handled at transform time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownCategoryPolicy {
+    /// Fail with `EncodingError::UnknownCategoryError`.
+    Error,
+    /// Encode unknown categories as all zeros.
+    Ignore,
+    /// Encode unknown categories in the `other` bucket.
+    Other,
+}
+
+/// The data type of the one-hot columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OneHotOutput {
+    Boolean,
+    UInt8,
+    Float64,
+}
+
+impl OneHotOutput {
+    fn dtype(&self) -> DataType {
+        match self {
+            OneHotOutput::Boolean => DataType::Boolean,
+            OneHotOutput::UInt8 => DataType::UInt8,
+            OneHotOutput::Float64 => DataType::Float64,
+        }
+    }
+}
+
+/// The categories learned for a single column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FittedCategories {
+    pub column: String,
+    /// The frequent categories, in their natural order, each receiving its own column.
+    pub categories: Vec<String>,
+    /// The categories seen less often than the minimum frequency, grouped in the `other` bucket.
+    pub infrequent: Vec<String>,
+}
+
+pub struct OneHotEncoder {
+    drop: OneHotDrop,
+    unknown_policy: UnknownCategoryPolicy,
+    min_frequency: Option<u32>,
+    output: OneHotOutput,
+    categories: Option<Vec<FittedCategories>>,
+}
+
+impl Default for OneHotEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OneHotEncoder {
+    pub fn new() -> Self {
+        OneHotEncoder {
+            drop: OneHotDrop::None,
+            unknown_policy: UnknownCategoryPolicy::Error,
+            min_frequency: None,
+            output: OneHotOutput::UInt8,
+            categories: None,
+        }
+    }
+
+    pub fn with_drop(mut self, drop: OneHotDrop) -> Self {
+        self.drop = drop;
+        self
+    }
+
+    pub fn with_unknown_policy(mut self, unknown_policy: UnknownCategoryPolicy) -> Self {
+        self.unknown_policy = unknown_policy;
+        self
+    }
+
+    /// Groups categories seen fewer than `min_frequency` times into the `other` bucket.
+    pub fn with_min_frequency(mut self, min_frequency: u32) -> Self {
+        self.min_frequency = Some(min_frequency);
+        self
+    }
+
+    pub fn with_output(mut self, output: OneHotOutput) -> Self {
+        self.output = output;
+        self
+    }
+
+    pub fn categories(&self) -> Option<&[FittedCategories]> {
+        self.categories.as_deref()
+    }
+
+    /// Returns the names of the columns produced for every fitted column, in output order.
+    pub fn feature_names_out(&self) -> Option<Vec<String>> {
+        self.categories.as_ref().map(|categories| {
+            categories
+                .iter()
+                .flat_map(|fitted| self.encoded_columns(fitted))
+                .map(|(name, _)| name)
+                .collect()
+        })
+    }
+
+    fn has_other_bucket(&self, fitted: &FittedCategories) -> bool {
+        !fitted.infrequent.is_empty() || self.unknown_policy == UnknownCategoryPolicy::Other
+    }
+
+    /// Returns the name and expression of every one-hot column produced for a fitted column.
+    fn encoded_columns(&self, fitted: &FittedCategories) -> Vec<(String, Expr)> {
+        let name = fitted.column.as_str();
+        let skip = match self.drop {
+            OneHotDrop::None => 0,
+            OneHotDrop::First => 1,
+        };
+
+        let mut encoded: Vec<(String, Expr)> = fitted
+            .categories
+            .iter()
+            .skip(skip)
+            .map(|category| {
+                let expr = category_expr(name)
+                    .eq(lit(category.as_str()))
+                    .fill_null(lit(false));
+                (format!("{name}_{category}"), expr)
+            })
+            .collect();
+
+        if self.has_other_bucket(fitted) {
+            let expr = match self.unknown_policy {
+                UnknownCategoryPolicy::Other => {
+                    let is_frequent = fitted.categories.iter().fold(lit(false), |acc, category| {
+                        acc.or(category_expr(name).eq(lit(category.as_str())))
+                    });
+                    category_expr(name).is_not_null().and(is_frequent.not())
+                },
+                _ => fitted
+                    .infrequent
+                    .iter()
+                    .fold(lit(false), |acc, category| {
+                        acc.or(category_expr(name).eq(lit(category.as_str())))
+                    })
+                    .fill_null(lit(false)),
+            };
+            encoded.push((format!("{name}_{OTHER_CATEGORY}"), expr));
+        }
+
+        encoded
+            .into_iter()
+            .map(|(column, expr)| {
+                let expr = expr.cast(self.output.dtype()).alias(column.as_str());
+                (column, expr)
+            })
+            .collect()
+    }
+}
+
+impl CategoricalEncoder for OneHotEncoder {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), EncodingError> {
+        validate_not_empty_df(df)?;
+        validate_categorical_columns(df)?;
+
+        let mut categories = Vec::with_capacity(df.width());
+        for name in df.get_column_names() {
+            let (frequent, infrequent): (Vec<_>, Vec<_>) = category_counts(df, name)?
+                .into_iter()
+                .partition(|(_, count)| self.min_frequency.is_none_or(|min| *count >= min));
+
+            categories.push(FittedCategories {
+                column: name.to_string(),
+                categories: frequent.into_iter().map(|(category, _)| category).collect(),
+                infrequent: infrequent
+                    .into_iter()
+                    .map(|(category, _)| category)
+                    .collect(),
+            });
+        }
+
+        let mut seen = HashSet::new();
+        let mut duplicates: Vec<String> = categories
+            .iter()
+            .flat_map(|fitted| self.encoded_columns(fitted))
+            .filter_map(|(name, _)| (!seen.insert(name.clone())).then_some(name))
+            .collect();
+        duplicates.sort();
+        duplicates.dedup();
+        if !duplicates.is_empty() {
+            return Err(EncodingError::DuplicateFeatureNamesError(
+                duplicates.join(", "),
+            ));
+        }
+
+        self.categories = Some(categories);
+
+        Ok(())
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, EncodingError> {
+        let categories = self
+            .categories
+            .as_ref()
+            .ok_or(EncodingError::NotFittedError)?;
+
+        let fitted: Vec<&str> = categories
+            .iter()
+            .map(|fitted| fitted.column.as_str())
+            .collect();
+        validate_columns_present(df, &fitted)?;
+
+        if self.unknown_policy == UnknownCategoryPolicy::Error {
+            let mut unknown_cols: Vec<String> = Vec::new();
+            for fitted in categories {
+                let known: Vec<String> = fitted
+                    .categories
+                    .iter()
+                    .chain(&fitted.infrequent)
+                    .cloned()
+                    .collect();
+                let unknown = unknown_categories(df, &fitted.column, &known)?;
+                if !unknown.is_empty() {
+                    unknown_cols.push(format!("{} ({})", fitted.column, unknown.join(", ")));
+                }
+            }
+
+            if !unknown_cols.is_empty() {
+                return Err(EncodingError::UnknownCategoryError(unknown_cols.join(", ")));
+            }
+        }
+
+        let exprs: Vec<Expr> = df
+            .get_column_names()
+            .into_iter()
+            .flat_map(|name| {
+                match categories
+                    .iter()
+                    .find(|fitted| fitted.column == name.as_str())
+                {
+                    Some(fitted) => self
+                        .encoded_columns(fitted)
+                        .into_iter()
+                        .map(|(_, expr)| expr)
+                        .collect(),
+                    None => vec![col(name.clone())],
+                }
+            })
+            .collect();
+
+        let encoded_df = df.clone().lazy().select(exprs).collect()?;
+
+        Ok(encoded_df)
+    }
+
+    fn reset(&mut self) {
+        self.categories = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "color" => ["red", "green", "red", "blue", "red", "green"],
+            "size" => [Some("s"), Some("m"), None, Some("m"), Some("l"), Some("m")]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let encoder = OneHotEncoder::new();
+        assert_eq!(encoder.drop, OneHotDrop::None);
+        assert_eq!(encoder.unknown_policy, UnknownCategoryPolicy::Error);
+        assert!(encoder.min_frequency.is_none());
+        assert_eq!(encoder.output, OneHotOutput::UInt8);
+        assert!(encoder.categories().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut encoder = OneHotEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        let categories = encoder.categories().unwrap();
+        assert_eq!(categories[0].categories, ["blue", "green", "red"]);
+        assert_eq!(categories[1].categories, ["l", "m", "s"]);
+        assert!(categories[0].infrequent.is_empty());
+    }
+
+    #[test]
+    fn test_fit_min_frequency() {
+        let mut encoder = OneHotEncoder::new().with_min_frequency(2);
+        encoder.fit(&create_df()).unwrap();
+
+        let categories = encoder.categories().unwrap();
+        assert_eq!(categories[0].categories, ["green", "red"]);
+        assert_eq!(categories[0].infrequent, ["blue"]);
+        assert_eq!(
+            encoder.feature_names_out().unwrap(),
+            [
+                "color_green",
+                "color_red",
+                "color_other",
+                "size_m",
+                "size_other"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fit_non_categorical() {
+        let df = df!["value" => [1.0f64, 2.0]].unwrap();
+        let mut encoder = OneHotEncoder::new();
+        assert!(matches!(
+            encoder.fit(&df),
+            Err(EncodingError::NonCategoricalError(_))
+        ));
+    }
+
+    #[test]
+    fn test_fit_duplicate_feature_names() {
+        let df = df!["color" => ["red", "other", "blue", "other"]].unwrap();
+        let mut encoder = OneHotEncoder::new().with_min_frequency(2);
+        assert!(matches!(
+            encoder.fit(&df),
+            Err(EncodingError::DuplicateFeatureNamesError(ref names)) if names == "color_other"
+        ));
+        assert!(encoder.categories().is_none());
+
+        let df = df![
+            "a" => ["b_c", "d"],
+            "a_b" => ["c", "e"]
+        ]
+        .unwrap();
+        assert!(matches!(
+            OneHotEncoder::new().fit(&df),
+            Err(EncodingError::DuplicateFeatureNamesError(ref names)) if names == "a_b_c"
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut encoder = OneHotEncoder::new();
+        let encoded_df = encoder.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "color_blue" => [0u8, 0, 0, 1, 0, 0],
+            "color_green" => [0u8, 1, 0, 0, 0, 1],
+            "color_red" => [1u8, 0, 1, 0, 1, 0],
+            "size_l" => [0u8, 0, 0, 0, 1, 0],
+            "size_m" => [0u8, 1, 0, 1, 0, 1],
+            "size_s" => [1u8, 0, 0, 0, 0, 0]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_drop_first_boolean() {
+        let df = df![
+            "id" => [1.5f64, 2.5],
+            "color" => ["red", "blue"]
+        ]
+        .unwrap();
+
+        let mut encoder = OneHotEncoder::new()
+            .with_drop(OneHotDrop::First)
+            .with_output(OneHotOutput::Boolean);
+        encoder.fit(&df.select(["color"]).unwrap()).unwrap();
+        let encoded_df = encoder.transform(&df).unwrap();
+
+        let expected = df![
+            "id" => [1.5f64, 2.5],
+            "color_red" => [true, false]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_unknown_error() {
+        let mut encoder = OneHotEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        let df = df![
+            "color" => ["purple", "red"],
+            "size" => ["m", "s"]
+        ]
+        .unwrap();
+        assert!(matches!(
+            encoder.transform(&df),
+            Err(EncodingError::UnknownCategoryError(ref cols)) if cols == "color (purple)"
+        ));
+    }
+
+    #[test]
+    fn test_transform_unknown_ignore() {
+        let mut encoder = OneHotEncoder::new()
+            .with_unknown_policy(UnknownCategoryPolicy::Ignore)
+            .with_output(OneHotOutput::Float64);
+        encoder
+            .fit(&create_df().select(["color"]).unwrap())
+            .unwrap();
+
+        let df = df!["color" => ["purple", "red"]].unwrap();
+        let encoded_df = encoder.transform(&df).unwrap();
+
+        let expected = df![
+            "color_blue" => [0.0f64, 0.0],
+            "color_green" => [0.0f64, 0.0],
+            "color_red" => [0.0f64, 1.0]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_unknown_other() {
+        let mut encoder = OneHotEncoder::new()
+            .with_unknown_policy(UnknownCategoryPolicy::Other)
+            .with_min_frequency(2);
+        encoder
+            .fit(&create_df().select(["color"]).unwrap())
+            .unwrap();
+
+        let df = df!["color" => [Some("purple"), Some("blue"), Some("red"), None]].unwrap();
+        let encoded_df = encoder.transform(&df).unwrap();
+
+        let expected = df![
+            "color_green" => [0u8, 0, 0, 0],
+            "color_red" => [0u8, 0, 1, 0],
+            "color_other" => [1u8, 1, 0, 0]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let encoder = OneHotEncoder::new();
+        assert!(matches!(
+            encoder.transform(&create_df()),
+            Err(EncodingError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut encoder = OneHotEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        encoder.reset();
+        assert!(encoder.categories().is_none());
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use polars::prelude::*;
+
+use super::categories::*;
+use super::error::*;
+use super::traits::CategoricalEncoder;
+use crate::validation::error::*;
+
+/// How the order of the categories is learned for columns without an explicit order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategoryOrder {
+    /// Categories are sorted in their natural order: numerically for integer columns and
+    /// lexically for string and categorical columns.
+    Lexical,
+    /// Categories are sorted from most to least frequent, breaking ties in their natural order.
+    Frequency,
+}
+
+pub struct OrdinalEncoder {
+    order: CategoryOrder,
+    explicit_categories: HashMap<String, Vec<String>>,
+    unknown_value: Option<i64>,
+    missing_value: Option<i64>,
+    categories: Option<Vec<(String, Vec<String>)>>,
+}
+
+impl Default for OrdinalEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrdinalEncoder {
+    pub fn new() -> Self {
+        OrdinalEncoder {
+            order: CategoryOrder::Lexical,
+            explicit_categories: HashMap::new(),
+            unknown_value: None,
+            missing_value: None,
+            categories: None,
+        }
+    }
+
+    pub fn with_order(mut self, order: CategoryOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the order of the categories of `column`, from code 0 upwards.
+    ///
+    /// `fit` rejects duplicate labels and columns absent from the fitted DataFrame.
+    pub fn with_categories(mut self, column: &str, categories: Vec<String>) -> Self {
+        self.explicit_categories
+            .insert(column.to_string(), categories);
+        self
+    }
+
+    /// Encodes categories that were not seen during fitting as `code`, instead of failing.
+    ///
+    /// The code must lie outside the learned codes `0..n_categories` of every column.
+    pub fn with_unknown_value(mut self, code: i64) -> Self {
+        self.unknown_value = Some(code);
+        self
+    }
+
+    /// Encodes missing values as `code`, instead of keeping them null.
+    ///
+    /// The code must lie outside the learned codes `0..n_categories` of every column.
+    pub fn with_missing_value(mut self, code: i64) -> Self {
+        self.missing_value = Some(code);
+        self
+    }
+
+    /// The ordered categories of every fitted column. A category's code is its position.
+    pub fn categories(&self) -> Option<&[(String, Vec<String>)]> {
+        self.categories.as_deref()
+    }
+
+    fn fitted_categories(&self) -> Result<&Vec<(String, Vec<String>)>, EncodingError> {
+        self.categories
+            .as_ref()
+            .ok_or(EncodingError::NotFittedError)
+    }
+
+    /// Maps the codes of the fitted columns back to their category labels.
+    ///
+    /// Codes that do not correspond to a category, including the unknown and missing codes,
+    /// become null.
+    pub fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, EncodingError> {
+        let categories = self.fitted_categories()?;
+        validate_encoded_columns(df, categories)?;
+
+        let mut decoded = df.clone().lazy();
+        for (name, labels) in categories {
+            let mapping = df![
+                LOOKUP_KEY_COLUMN => (0..labels.len() as i64).collect::<Vec<_>>(),
+                LOOKUP_VALUE_COLUMN => labels
+            ]?;
+
+            decoded = lookup_categories(decoded, col(name.as_str()).cast(DataType::Int64), mapping)
+                .with_column(col(LOOKUP_VALUE_COLUMN).alias(name.as_str()))
+                .drop([LOOKUP_VALUE_COLUMN]);
+        }
+
+        Ok(decoded.collect()?)
+    }
+}
+
+/// Validates that every fitted column is present in the DataFrame.
+fn validate_encoded_columns(
+    df: &DataFrame,
+    categories: &[(String, Vec<String>)],
+) -> Result<(), ValidationError> {
+    let fitted: Vec<&str> = categories.iter().map(|(name, _)| name.as_str()).collect();
+    validate_columns_present(df, &fitted)
+}
+
+impl CategoricalEncoder for OrdinalEncoder {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), EncodingError> {
+        validate_not_empty_df(df)?;
+        validate_categorical_columns(df)?;
+
+        let mut explicit_cols: Vec<&str> = self
+            .explicit_categories
+            .keys()
+            .map(|name| name.as_str())
+            .collect();
+        explicit_cols.sort();
+        validate_columns_present(df, &explicit_cols)?;
+
+        let mut duplicate_cols: Vec<&str> = self
+            .explicit_categories
+            .iter()
+            .filter(|(_, labels)| {
+                let mut seen = HashSet::with_capacity(labels.len());
+                !labels.iter().all(|label| seen.insert(label))
+            })
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if !duplicate_cols.is_empty() {
+            duplicate_cols.sort();
+            return Err(EncodingError::InvalidParameterError(format!(
+                "the explicit categories of {} contain duplicate labels",
+                duplicate_cols.join(", ")
+            )));
+        }
+
+        let mut categories = Vec::with_capacity(df.width());
+        let mut unknown_cols: Vec<String> = Vec::new();
+
+        for name in df.get_column_names() {
+            let ordered = match self.explicit_categories.get(name.as_str()) {
+                Some(explicit) => {
+                    let unknown = unknown_categories(df, name, explicit)?;
+                    if !unknown.is_empty() {
+                        unknown_cols.push(format!("{name} ({})", unknown.join(", ")));
+                    }
+                    explicit.clone()
+                },
+                None => {
+                    let mut counts = category_counts(df, name)?;
+                    if self.order == CategoryOrder::Frequency {
+                        // The sort is stable, so ties keep their natural order.
+                        counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+                    }
+                    counts.into_iter().map(|(category, _)| category).collect()
+                },
+            };
+
+            categories.push((name.to_string(), ordered));
+        }
+
+        if !unknown_cols.is_empty() {
+            return Err(EncodingError::UnknownCategoryError(unknown_cols.join(", ")));
+        }
+
+        let n_codes = categories
+            .iter()
+            .map(|(_, labels)| labels.len())
+            .max()
+            .unwrap_or(0) as i64;
+        for (parameter, code) in [
+            ("unknown", self.unknown_value),
+            ("missing", self.missing_value),
+        ] {
+            if let Some(code) = code.filter(|code| (0..n_codes).contains(code)) {
+                return Err(EncodingError::InvalidParameterError(format!(
+                    "the {parameter} code {code} overlaps the learned codes 0..{n_codes}"
+                )));
+            }
+        }
+
+        self.categories = Some(categories);
+
+        Ok(())
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, EncodingError> {
+        let categories = self.fitted_categories()?;
+        validate_encoded_columns(df, categories)?;
+
+        if self.unknown_value.is_none() {
+            let mut unknown_cols: Vec<String> = Vec::new();
+            for (name, labels) in categories {
+                let unknown = unknown_categories(df, name, labels)?;
+                if !unknown.is_empty() {
+                    unknown_cols.push(format!("{name} ({})", unknown.join(", ")));
+                }
+            }
+
+            if !unknown_cols.is_empty() {
+                return Err(EncodingError::UnknownCategoryError(unknown_cols.join(", ")));
+            }
+        }
+
+        let unknown = self
+            .unknown_value
+            .map_or(lit(NULL), lit)
+            .cast(DataType::Int64);
+        let missing = self
+            .missing_value
+            .map_or(lit(NULL), lit)
+            .cast(DataType::Int64);
+
+        let mut encoded = df.clone().lazy();
+        for (name, labels) in categories {
+            let mapping = df![
+                LOOKUP_KEY_COLUMN => labels,
+                LOOKUP_VALUE_COLUMN => (0..labels.len() as i64).collect::<Vec<_>>()
+            ]?;
+
+            encoded = lookup_categories(encoded, category_expr(name), mapping)
+                .with_column(
+                    when(col(name.as_str()).is_null())
+                        .then(missing.clone())
+                        .otherwise(col(LOOKUP_VALUE_COLUMN).fill_null(unknown.clone()))
+                        .alias(name.as_str()),
+                )
+                .drop([LOOKUP_VALUE_COLUMN]);
+        }
+
+        Ok(encoded.collect()?)
+    }
+
+    fn reset(&mut self) {
+        self.categories = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "size" => [Some("m"), Some("s"), Some("l"), Some("m"), None, Some("m")],
+            "grade" => ["b", "a", "b", "c", "a", "b"]
+        ]
+        .unwrap()
+    }
+
+    fn size_order() -> Vec<String> {
+        vec!["s".to_string(), "m".to_string(), "l".to_string()]
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let encoder = OrdinalEncoder::new();
+        assert_eq!(encoder.order, CategoryOrder::Lexical);
+        assert!(encoder.unknown_value.is_none());
+        assert!(encoder.missing_value.is_none());
+        assert!(encoder.categories().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_lexical() {
+        let mut encoder = OrdinalEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        let categories = encoder.categories().unwrap();
+        assert_eq!(categories[0].1, ["l", "m", "s"]);
+        assert_eq!(categories[1].1, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_fit_frequency() {
+        let mut encoder = OrdinalEncoder::new().with_order(CategoryOrder::Frequency);
+        encoder.fit(&create_df()).unwrap();
+
+        let categories = encoder.categories().unwrap();
+        assert_eq!(categories[0].1, ["m", "l", "s"]);
+        assert_eq!(categories[1].1, ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_fit_explicit() {
+        let mut encoder = OrdinalEncoder::new().with_categories("size", size_order());
+        encoder.fit(&create_df()).unwrap();
+
+        let categories = encoder.categories().unwrap();
+        assert_eq!(categories[0].1, ["s", "m", "l"]);
+        assert_eq!(categories[1].1, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_fit_explicit_missing_category() {
+        let mut encoder =
+            OrdinalEncoder::new().with_categories("size", vec!["s".to_string(), "m".to_string()]);
+        assert!(matches!(
+            encoder.fit(&create_df()),
+            Err(EncodingError::UnknownCategoryError(ref cols)) if cols == "size (l)"
+        ));
+    }
+
+    #[test]
+    fn test_fit_explicit_invalid() {
+        let mut encoder = OrdinalEncoder::new().with_categories(
+            "size",
+            vec!["s".to_string(), "m".to_string(), "s".to_string()],
+        );
+        assert!(matches!(
+            encoder.fit(&create_df()),
+            Err(EncodingError::InvalidParameterError(_))
+        ));
+        assert!(encoder.categories().is_none());
+
+        let mut encoder = OrdinalEncoder::new().with_categories("absent", size_order());
+        assert!(matches!(
+            encoder.fit(&create_df()),
+            Err(EncodingError::ValidationError(
+                ValidationError::MissingColumnsError(ref cols)
+            )) if cols == "absent"
+        ));
+    }
+
+    #[test]
+    fn test_fit_integer_order() {
+        let df = df!["code" => [2i64, 10, 1]].unwrap();
+        let mut encoder = OrdinalEncoder::new();
+        let encoded_df = encoder.fit_transform(&df).unwrap();
+
+        assert_eq!(encoder.categories().unwrap()[0].1, ["1", "2", "10"]);
+        assert_eq!(encoded_df, df!["code" => [1i64, 2, 0]].unwrap());
+    }
+
+    #[test]
+    fn test_fit_overlapping_codes() {
+        let mut encoder = OrdinalEncoder::new().with_unknown_value(0);
+        assert!(matches!(
+            encoder.fit(&create_df()),
+            Err(EncodingError::InvalidParameterError(_))
+        ));
+        assert!(encoder.categories().is_none());
+
+        let mut encoder = OrdinalEncoder::new().with_missing_value(2);
+        assert!(matches!(
+            encoder.fit(&create_df()),
+            Err(EncodingError::InvalidParameterError(_))
+        ));
+
+        let mut encoder = OrdinalEncoder::new().with_missing_value(3);
+        assert!(encoder.fit(&create_df()).is_ok());
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut encoder = OrdinalEncoder::new().with_categories("size", size_order());
+        let encoded_df = encoder.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "size" => [Some(1i64), Some(0), Some(2), Some(1), None, Some(1)],
+            "grade" => [1i64, 0, 1, 2, 0, 1]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_unknown_and_missing_codes() {
+        let mut encoder = OrdinalEncoder::new()
+            .with_unknown_value(-1)
+            .with_missing_value(-2);
+        encoder.fit(&create_df().select(["size"]).unwrap()).unwrap();
+
+        let df = df!["size" => [Some("xl"), None, Some("s")]].unwrap();
+        let encoded_df = encoder.transform(&df).unwrap();
+
+        let expected = df!["size" => [-1i64, -2, 2]].unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_unknown_error() {
+        let mut encoder = OrdinalEncoder::new();
+        encoder.fit(&create_df().select(["size"]).unwrap()).unwrap();
+
+        let df = df!["size" => ["xl", "s"]].unwrap();
+        assert!(matches!(
+            encoder.transform(&df),
+            Err(EncodingError::UnknownCategoryError(_))
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let encoder = OrdinalEncoder::new();
+        assert!(matches!(
+            encoder.transform(&create_df()),
+            Err(EncodingError::NotFittedError)
+        ));
+    }
+
+    // Test inverse_transform method
+    #[test]
+    fn test_inverse_transform() {
+        let mut encoder = OrdinalEncoder::new()
+            .with_categories("size", size_order())
+            .with_unknown_value(-1);
+        let df = create_df();
+        let encoded_df = encoder.fit_transform(&df).unwrap();
+
+        assert_eq!(encoder.inverse_transform(&encoded_df).unwrap(), df);
+
+        let codes = df![
+            "size" => [2i64, -1],
+            "grade" => [0i64, 1]
+        ]
+        .unwrap();
+        let expected = df![
+            "size" => [Some("l"), None],
+            "grade" => ["a", "b"]
+        ]
+        .unwrap();
+        assert_eq!(encoder.inverse_transform(&codes).unwrap(), expected);
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut encoder = OrdinalEncoder::new();
+        encoder.fit(&create_df()).unwrap();
+
+        encoder.reset();
+        assert!(encoder.categories().is_none());
+    }
+}
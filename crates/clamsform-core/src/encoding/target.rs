@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+
+use polars::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use super::categories::*;
+use super::error::*;
+use crate::validation::error::*;
+
+/// The encoding given to categories that were not seen during fitting, and to missing values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnseenCategoryFallback {
+    /// The mean of the target over all fitted rows.
+    GlobalMean,
+    /// A fixed value.
+    Value(f64),
+    /// Fail with `EncodingError::UnknownCategoryError`. Missing values still use the global mean.
+    Error,
+}
+
+/// Replaces every category with the mean of the target over the rows of that category,
+/// shrunk towards the global mean of the target.
+///
+/// A category seen `n` times with target mean `m` is encoded as
+/// `(n * m + smoothing * global_mean) / (n + smoothing)`.
+///
+/// `fit_transform` cross-fits the encodings: the rows are split in `n_folds` folds, and
+/// every fold is encoded with the statistics learned from the other folds, so that no row
+/// is encoded using its own target.
+pub struct TargetEncoder {
+    smoothing: f64,
+    n_folds: usize,
+    shuffle: bool,
+    seed: u64,
+    fallback: UnseenCategoryFallback,
+    global_mean: Option<f64>,
+    encodings: Option<Vec<(String, HashMap<String, f64>)>>,
+}
+
+impl Default for TargetEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The sum of the target and the number of rows of every category of a column.
+type CategoryStats = HashMap<String, (f64, f64)>;
+
+impl TargetEncoder {
+    pub fn new() -> Self {
+        TargetEncoder {
+            smoothing: 1.0,
+            n_folds: 5,
+            shuffle: true,
+            seed: 0,
+            fallback: UnseenCategoryFallback::GlobalMean,
+            global_mean: None,
+            encodings: None,
+        }
+    }
+
+    /// Sets the weight of the global mean in the encodings. Must be non-negative.
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Sets the number of folds used by `fit_transform`. Must be at least 2.
+    pub fn with_n_folds(mut self, n_folds: usize) -> Self {
+        self.n_folds = n_folds;
+        self
+    }
+
+    /// Shuffles the rows before splitting them in folds. Enabled by default.
+    pub fn with_shuffle(mut self, shuffle: bool) -> Self {
+        self.shuffle = shuffle;
+        self
+    }
+
+    /// Sets the seed of the random generator used to shuffle the rows.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_fallback(mut self, fallback: UnseenCategoryFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// The mean of the target over all fitted rows.
+    pub fn global_mean(&self) -> Option<f64> {
+        self.global_mean
+    }
+
+    /// The encoding of every category of every fitted column.
+    pub fn encodings(&self) -> Option<&[(String, HashMap<String, f64>)]> {
+        self.encodings.as_deref()
+    }
+
+    fn validate_parameters(&self) -> Result<(), EncodingError> {
+        if self.smoothing.is_nan() || self.smoothing < 0.0 {
+            return Err(EncodingError::InvalidParameterError(format!(
+                "smoothing must be non-negative, got {}",
+                self.smoothing
+            )));
+        }
+        if self.n_folds < 2 {
+            return Err(EncodingError::InvalidParameterError(format!(
+                "n_folds must be at least 2, got {}",
+                self.n_folds
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn encode(&self, stats: &CategoryStats, global_mean: f64) -> HashMap<String, f64> {
+        stats
+            .iter()
+            .map(|(category, (sum, count))| {
+                let encoding = (sum + self.smoothing * global_mean) / (count + self.smoothing);
+                (category.clone(), encoding)
+            })
+            .collect()
+    }
+
+    fn encode_column(
+        &self,
+        categories: &StringChunked,
+        rows: &[usize],
+        encodings: &HashMap<String, f64>,
+        global_mean: f64,
+    ) -> Vec<f64> {
+        let fallback = match self.fallback {
+            UnseenCategoryFallback::Value(value) => value,
+            _ => global_mean,
+        };
+
+        rows.iter()
+            .map(|&row| match categories.get(row) {
+                Some(category) => encodings.get(category).copied().unwrap_or(fallback),
+                None => global_mean,
+            })
+            .collect()
+    }
+
+    /// Learns the encodings of every column from the whole DataFrame.
+    pub fn fit(&mut self, df: &DataFrame, target: &Series) -> Result<(), EncodingError> {
+        self.validate_parameters()?;
+        validate_not_empty_df(df)?;
+        validate_categorical_columns(df)?;
+        let target = target_values(df, target)?;
+
+        let rows: Vec<usize> = (0..df.height()).collect();
+        let global_mean = mean(&target, &rows);
+
+        let mut encodings = Vec::with_capacity(df.width());
+        for name in df.get_column_names() {
+            let categories = column_categories(df, name)?;
+            let stats = category_stats(&categories, &target, &rows);
+            encodings.push((name.to_string(), self.encode(&stats, global_mean)));
+        }
+
+        self.global_mean = Some(global_mean);
+        self.encodings = Some(encodings);
+
+        Ok(())
+    }
+
+    /// Encodes the fitted columns of the DataFrame as Float64 columns.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, EncodingError> {
+        let (Some(encodings), Some(global_mean)) = (&self.encodings, self.global_mean) else {
+            return Err(EncodingError::NotFittedError);
+        };
+
+        let fitted: Vec<&str> = encodings.iter().map(|(name, _)| name.as_str()).collect();
+        validate_columns_present(df, &fitted)?;
+
+        if self.fallback == UnseenCategoryFallback::Error {
+            let mut unknown_cols: Vec<String> = Vec::new();
+            for (name, column_encodings) in encodings {
+                let known: Vec<String> = column_encodings.keys().cloned().collect();
+                let unknown = unknown_categories(df, name, &known)?;
+                if !unknown.is_empty() {
+                    unknown_cols.push(format!("{name} ({})", unknown.join(", ")));
+                }
+            }
+
+            if !unknown_cols.is_empty() {
+                return Err(EncodingError::UnknownCategoryError(unknown_cols.join(", ")));
+            }
+        }
+
+        let rows: Vec<usize> = (0..df.height()).collect();
+        let mut encoded_df = df.clone();
+        for (name, column_encodings) in encodings {
+            let categories = column_categories(df, name)?;
+            let encoded = self.encode_column(&categories, &rows, column_encodings, global_mean);
+            encoded_df.replace(name, Series::new(name.into(), encoded))?;
+        }
+
+        Ok(encoded_df)
+    }
+
+    /// Learns the encodings from the whole DataFrame, and returns the DataFrame encoded
+    /// out-of-fold, so that no row is encoded using its own target.
+    pub fn fit_transform(
+        &mut self,
+        df: &DataFrame,
+        target: &Series,
+    ) -> Result<DataFrame, EncodingError> {
+        let n_rows = df.height();
+        if n_rows < self.n_folds {
+            return Err(EncodingError::InvalidParameterError(format!(
+                "cannot split {n_rows} rows in {} folds",
+                self.n_folds
+            )));
+        }
+
+        self.fit(df, target)?;
+        let target = target_values(df, target)?;
+
+        let mut rows: Vec<usize> = (0..n_rows).collect();
+        if self.shuffle {
+            let mut rng = StdRng::seed_from_u64(self.seed);
+            rows.shuffle(&mut rng);
+        }
+        let folds: Vec<&[usize]> = (0..self.n_folds)
+            .map(|fold| &rows[fold * n_rows / self.n_folds..(fold + 1) * n_rows / self.n_folds])
+            .collect();
+
+        let mut encoded_df = df.clone();
+        for name in df.get_column_names() {
+            let categories = column_categories(df, name)?;
+            let mut encoded = vec![0.0; n_rows];
+
+            for (fold, held_out) in folds.iter().enumerate() {
+                let train_rows: Vec<usize> = folds
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != fold)
+                    .flat_map(|(_, rows)| rows.iter().copied())
+                    .collect();
+
+                let global_mean = mean(&target, &train_rows);
+                let stats = category_stats(&categories, &target, &train_rows);
+                let fold_encodings = self.encode(&stats, global_mean);
+                let fold_encoded =
+                    self.encode_column(&categories, held_out, &fold_encodings, global_mean);
+
+                for (&row, value) in held_out.iter().zip(fold_encoded) {
+                    encoded[row] = value;
+                }
+            }
+
+            encoded_df.replace(name, Series::new(name.clone(), encoded))?;
+        }
+
+        Ok(encoded_df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.global_mean = None;
+        self.encodings = None;
+    }
+}
+
+/// Validates the target against the DataFrame and returns its values as `f64`.
+fn target_values(df: &DataFrame, target: &Series) -> Result<Vec<f64>, EncodingError> {
+    if target.len() != df.height() {
+        return Err(EncodingError::InvalidParameterError(format!(
+            "the target has {} rows but the DataFrame has {}",
+            target.len(),
+            df.height()
+        )));
+    }
+    if !(target.dtype().is_primitive_numeric() || target.dtype() == &DataType::Boolean) {
+        Err(ValidationError::NonNumericError(target.name().to_string()))?;
+    }
+
+    let target = target.cast(&DataType::Float64)?;
+    let target_df = DataFrame::new(vec![target.clone().into_column()])?;
+    validate_missing_values(&target_df)?;
+    validate_nan_values(&target_df)?;
+    validate_infinite_values(&target_df)?;
+
+    Ok(target.f64()?.into_no_null_iter().collect())
+}
+
+/// Renders the values of a column as category labels.
+fn column_categories(df: &DataFrame, name: &str) -> Result<StringChunked, EncodingError> {
+    let categories = df.clone().lazy().select([category_expr(name)]).collect()?;
+
+    Ok(categories.column(name)?.str()?.clone())
+}
+
+fn mean(target: &[f64], rows: &[usize]) -> f64 {
+    rows.iter().map(|&row| target[row]).sum::<f64>() / rows.len() as f64
+}
+
+/// Accumulates the target statistics of every non-null category over the given rows.
+fn category_stats(categories: &StringChunked, target: &[f64], rows: &[usize]) -> CategoryStats {
+    let mut stats = CategoryStats::new();
+    for &row in rows {
+        if let Some(category) = categories.get(row) {
+            let entry = stats.entry(category.to_string()).or_insert((0.0, 0.0));
+            entry.0 += target[row];
+            entry.1 += 1.0;
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "city" => ["a", "a", "b", "b", "b", "c"],
+            "flag" => [Some(true), Some(true), Some(false), None, Some(false), Some(true)]
+        ]
+        .unwrap()
+    }
+
+    fn create_target() -> Series {
+        Series::new("y".into(), [1.0f64, 3.0, 2.0, 4.0, 6.0, 2.0])
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let encoder = TargetEncoder::new();
+        assert_eq!(encoder.smoothing, 1.0);
+        assert_eq!(encoder.n_folds, 5);
+        assert_eq!(encoder.fallback, UnseenCategoryFallback::GlobalMean);
+        assert!(encoder.global_mean().is_none());
+        assert!(encoder.encodings().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut encoder = TargetEncoder::new().with_smoothing(2.0);
+        encoder.fit(&create_df(), &create_target()).unwrap();
+
+        // The global mean is 18 / 6 = 3.
+        assert_abs_diff_eq!(encoder.global_mean().unwrap(), 3.0);
+
+        let city = &encoder.encodings().unwrap()[0].1;
+        // a: (4 + 2 * 3) / (2 + 2), b: (12 + 2 * 3) / (3 + 2), c: (2 + 2 * 3) / (1 + 2)
+        assert_abs_diff_eq!(city["a"], 2.5);
+        assert_abs_diff_eq!(city["b"], 3.6);
+        assert_abs_diff_eq!(city["c"], 8.0 / 3.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fit_binary_target() {
+        let mut encoder = TargetEncoder::new().with_smoothing(0.0);
+        let target = Series::new("y".into(), [true, false, true, true, true, false]);
+        encoder.fit(&create_df(), &target).unwrap();
+
+        let city = &encoder.encodings().unwrap()[0].1;
+        assert_abs_diff_eq!(city["a"], 0.5);
+        assert_abs_diff_eq!(city["b"], 1.0);
+        assert_abs_diff_eq!(city["c"], 0.0);
+    }
+
+    #[test]
+    fn test_fit_invalid_target() {
+        let mut encoder = TargetEncoder::new();
+        let short_target = Series::new("y".into(), [1.0f64, 2.0]);
+        assert!(matches!(
+            encoder.fit(&create_df(), &short_target),
+            Err(EncodingError::InvalidParameterError(_))
+        ));
+
+        let missing_target = Series::new("y".into(), [Some(1.0f64), None, None, None, None, None]);
+        assert!(matches!(
+            encoder.fit(&create_df(), &missing_target),
+            Err(EncodingError::ValidationError(
+                ValidationError::MissingValuesError(_)
+            ))
+        ));
+
+        let string_target = Series::new("y".into(), ["a", "b", "c", "d", "e", "f"]);
+        assert!(matches!(
+            encoder.fit(&create_df(), &string_target),
+            Err(EncodingError::ValidationError(
+                ValidationError::NonNumericError(_)
+            ))
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_unseen_and_missing() {
+        let mut encoder = TargetEncoder::new().with_smoothing(0.0);
+        encoder.fit(&create_df(), &create_target()).unwrap();
+
+        let df = df![
+            "city" => ["a", "z"],
+            "flag" => [None, Some(true)]
+        ]
+        .unwrap();
+        let encoded_df = encoder.transform(&df).unwrap();
+
+        let expected = df![
+            "city" => [2.0f64, 3.0],
+            "flag" => [3.0f64, 2.0]
+        ]
+        .unwrap();
+        assert_eq!(encoded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_fallback_value() {
+        let mut encoder = TargetEncoder::new().with_fallback(UnseenCategoryFallback::Value(-1.0));
+        encoder.fit(&create_df(), &create_target()).unwrap();
+
+        let df = df!["city" => ["z"], "flag" => [true]].unwrap();
+        let encoded_df = encoder.transform(&df).unwrap();
+        assert_eq!(
+            encoded_df.column("city").unwrap().get(0).unwrap(),
+            AnyValue::Float64(-1.0)
+        );
+    }
+
+    #[test]
+    fn test_transform_fallback_error() {
+        let mut encoder = TargetEncoder::new().with_fallback(UnseenCategoryFallback::Error);
+        encoder.fit(&create_df(), &create_target()).unwrap();
+
+        let df = df!["city" => ["z"], "flag" => [true]].unwrap();
+        assert!(matches!(
+            encoder.transform(&df),
+            Err(EncodingError::UnknownCategoryError(ref cols)) if cols == "city (z)"
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let encoder = TargetEncoder::new();
+        assert!(matches!(
+            encoder.transform(&create_df()),
+            Err(EncodingError::NotFittedError)
+        ));
+    }
+
+    // Test fit_transform method
+    #[test]
+    fn test_fit_transform_cross_fitting() {
+        let df = df!["city" => ["a", "a", "b", "b"]].unwrap();
+        let target = Series::new("y".into(), [1.0f64, 3.0, 5.0, 7.0]);
+
+        let mut encoder = TargetEncoder::new()
+            .with_smoothing(0.0)
+            .with_n_folds(4)
+            .with_shuffle(false);
+        let encoded_df = encoder.fit_transform(&df, &target).unwrap();
+
+        // With leave-one-out folds, every row is encoded with the other row of its category.
+        let expected = df!["city" => [3.0f64, 1.0, 7.0, 5.0]].unwrap();
+        assert_eq!(encoded_df, expected);
+
+        // The encodings used by transform are learned from all rows.
+        let city = &encoder.encodings().unwrap()[0].1;
+        assert_abs_diff_eq!(city["a"], 2.0);
+        assert_abs_diff_eq!(city["b"], 6.0);
+    }
+
+    #[test]
+    fn test_fit_transform_seed_is_reproducible() {
+        let encode = |seed: u64| {
+            TargetEncoder::new()
+                .with_n_folds(3)
+                .with_seed(seed)
+                .fit_transform(&create_df(), &create_target())
+                .unwrap()
+        };
+
+        assert_eq!(encode(7), encode(7));
+    }
+
+    #[test]
+    fn test_fit_transform_too_few_rows() {
+        let mut encoder = TargetEncoder::new().with_n_folds(10);
+        assert!(matches!(
+            encoder.fit_transform(&create_df(), &create_target()),
+            Err(EncodingError::InvalidParameterError(_))
+        ));
+        assert!(encoder.global_mean().is_none());
+        assert!(encoder.encodings().is_none());
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut encoder = TargetEncoder::new();
+        encoder.fit(&create_df(), &create_target()).unwrap();
+
+        encoder.reset();
+        assert!(encoder.global_mean().is_none());
+        assert!(encoder.encodings().is_none());
+    }
+}
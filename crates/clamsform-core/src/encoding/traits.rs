@@ -0,0 +1,20 @@
+use polars::prelude::DataFrame;
+
+use super::error::EncodingError;
+
+pub trait CategoricalEncoder {
+    /// Learns the categories of every column in the dataframe
+    fn fit(&mut self, df: &DataFrame) -> Result<(), EncodingError>;
+
+    /// Encodes the dataframe using the learned categories
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, EncodingError>;
+
+    /// Learns the categories and returns the encoded dataframe
+    fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, EncodingError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    fn reset(&mut self);
+}
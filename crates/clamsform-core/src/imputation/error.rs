@@ -0,0 +1,32 @@
+use polars::prelude::*;
+use thiserror::Error;
+
+use crate::validation::error::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum ImputationError {
+    #[error(
+        "The imputer has not been fitted. \
+        Call `fit` before attempting to transform a DataFrame."
+    )]
+    NotFittedError,
+
+    #[error(
+        "The column(s) {0} contain no observed values. \
+        A fill value cannot be learned from a column that is entirely missing. \
+        Consider removing these features or using a constant fill value."
+    )]
+    AllMissingError(String),
+
+    #[error("Invalid imputer parameter: {0}")]
+    InvalidParameterError(String),
+
+    #[error("The regression estimator failed: {0}")]
+    EstimatorError(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
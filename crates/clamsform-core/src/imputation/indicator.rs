@@ -0,0 +1,314 @@
+use polars::prelude::*;
+
+use super::error::*;
+use super::traits::Imputer;
+use crate::validation::error::*;
+
+/// Which columns receive an indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicatorFeatures {
+    /// Only the columns that contained missing values at fit time.
+    MissingOnly,
+    /// Every column seen at fit time.
+    All,
+}
+
+/// The data type of the indicator columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicatorOutput {
+    Boolean,
+    UInt8,
+}
+
+/// Generates `<col>_missing` columns flagging the values that are null, and optionally `NaN`
+/// in float columns.
+pub struct MissingIndicator {
+    features: IndicatorFeatures,
+    output: IndicatorOutput,
+    nan_as_missing: bool,
+    indicator_columns: Option<Vec<String>>,
+}
+
+impl Default for MissingIndicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MissingIndicator {
+    pub fn new() -> Self {
+        MissingIndicator {
+            features: IndicatorFeatures::MissingOnly,
+            output: IndicatorOutput::Boolean,
+            nan_as_missing: false,
+            indicator_columns: None,
+        }
+    }
+
+    pub fn with_features(mut self, features: IndicatorFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    pub fn with_output(mut self, output: IndicatorOutput) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Treats `NaN` values in float columns as missing. Disabled by default, matching
+    /// `SimpleImputer::with_nan_as_missing`.
+    pub fn with_nan_as_missing(mut self, nan_as_missing: bool) -> Self {
+        self.nan_as_missing = nan_as_missing;
+        self
+    }
+
+    /// The columns that receive an indicator, learned at fit time.
+    pub fn indicator_columns(&self) -> Option<&[String]> {
+        self.indicator_columns.as_deref()
+    }
+
+    fn missing_expr(&self, name: &str, dtype: &DataType) -> Expr {
+        if self.nan_as_missing && dtype.is_float() {
+            col(name).is_null().or(col(name).is_nan())
+        } else {
+            col(name).is_null()
+        }
+    }
+
+    /// Learns which columns receive an indicator.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), ImputationError> {
+        validate_not_empty_df(df)?;
+
+        let missing_flags = df
+            .clone()
+            .lazy()
+            .select(
+                df.get_columns()
+                    .iter()
+                    .map(|col| self.missing_expr(col.name(), col.dtype()).any(true))
+                    .collect::<Vec<_>>(),
+            )
+            .collect()?;
+
+        let indicator_columns = missing_flags
+            .get_columns()
+            .iter()
+            .filter(|flag| {
+                self.features == IndicatorFeatures::All
+                    || matches!(flag.get(0), Ok(AnyValue::Boolean(true)))
+            })
+            .map(|flag| flag.name().to_string())
+            .collect();
+
+        self.indicator_columns = Some(indicator_columns);
+
+        Ok(())
+    }
+
+    /// Returns a DataFrame holding only the indicator columns.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, ImputationError> {
+        let indicator_columns = self
+            .indicator_columns
+            .as_ref()
+            .ok_or(ImputationError::NotFittedError)?;
+
+        let fitted: Vec<&str> = indicator_columns.iter().map(|name| name.as_str()).collect();
+        validate_columns_present(df, &fitted)?;
+
+        let mut indicator_exprs: Vec<Expr> = Vec::with_capacity(indicator_columns.len());
+        for name in indicator_columns {
+            let mut expr = self.missing_expr(name, df.column(name)?.dtype());
+            if self.output == IndicatorOutput::UInt8 {
+                expr = expr.cast(DataType::UInt8);
+            }
+            indicator_exprs.push(expr.alias(format!("{name}_missing")));
+        }
+
+        let indicator_df = df.clone().lazy().select(indicator_exprs).collect()?;
+
+        Ok(indicator_df)
+    }
+
+    /// Learns the indicator columns and returns the indicators.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, ImputationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.indicator_columns = None;
+    }
+}
+
+/// Combines an imputer with a `MissingIndicator`, appending the indicators to the imputed DataFrame.
+pub struct WithMissingIndicator<I: Imputer> {
+    imputer: I,
+    indicator: MissingIndicator,
+}
+
+impl<I: Imputer> WithMissingIndicator<I> {
+    pub fn new(imputer: I, indicator: MissingIndicator) -> Self {
+        WithMissingIndicator { imputer, indicator }
+    }
+
+    pub fn imputer(&self) -> &I {
+        &self.imputer
+    }
+
+    pub fn indicator(&self) -> &MissingIndicator {
+        &self.indicator
+    }
+}
+
+impl<I: Imputer> Imputer for WithMissingIndicator<I> {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), ImputationError> {
+        self.indicator.fit(df)?;
+        self.imputer.fit(df)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, ImputationError> {
+        let indicator_df = self.indicator.transform(df)?;
+        let imputed_df = self.imputer.transform(df)?;
+
+        Ok(imputed_df.hstack(indicator_df.get_columns())?)
+    }
+
+    fn reset(&mut self) {
+        self.imputer.reset();
+        self.indicator.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imputation::simple::*;
+
+    fn create_missing_df() -> DataFrame {
+        df![
+            "feature1" => [Some(1.0f64), None, Some(3.0)],
+            "feature2" => [4.0f64, f64::NAN, 6.0],
+            "complete" => [7.0f64, 8.0, 9.0],
+            "label" => [Some("a"), Some("b"), None]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let indicator = MissingIndicator::new();
+        assert_eq!(indicator.features, IndicatorFeatures::MissingOnly);
+        assert_eq!(indicator.output, IndicatorOutput::Boolean);
+        assert!(!indicator.nan_as_missing);
+        assert!(indicator.indicator_columns().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_missing_only() {
+        let mut indicator = MissingIndicator::new();
+        indicator.fit(&create_missing_df()).unwrap();
+        assert_eq!(
+            indicator.indicator_columns().unwrap(),
+            ["feature1", "label"]
+        );
+    }
+
+    #[test]
+    fn test_fit_nan_as_missing() {
+        let mut indicator = MissingIndicator::new().with_nan_as_missing(true);
+        indicator.fit(&create_missing_df()).unwrap();
+        assert_eq!(
+            indicator.indicator_columns().unwrap(),
+            ["feature1", "feature2", "label"]
+        );
+    }
+
+    #[test]
+    fn test_fit_all() {
+        let mut indicator = MissingIndicator::new().with_features(IndicatorFeatures::All);
+        indicator.fit(&create_missing_df()).unwrap();
+        assert_eq!(indicator.indicator_columns().unwrap().len(), 4);
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_boolean() {
+        let mut indicator = MissingIndicator::new().with_nan_as_missing(true);
+        let indicator_df = indicator.fit_transform(&create_missing_df()).unwrap();
+
+        let expected = df![
+            "feature1_missing" => [false, true, false],
+            "feature2_missing" => [false, true, false],
+            "label_missing" => [false, false, true]
+        ]
+        .unwrap();
+        assert_eq!(indicator_df, expected);
+    }
+
+    #[test]
+    fn test_transform_uint8() {
+        let mut indicator = MissingIndicator::new()
+            .with_nan_as_missing(true)
+            .with_output(IndicatorOutput::UInt8);
+        indicator.fit(&create_missing_df()).unwrap();
+
+        let df = df![
+            "feature1" => [None, Some(2.0f64)],
+            "feature2" => [1.0f64, 2.0],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        let indicator_df = indicator.transform(&df).unwrap();
+
+        let expected = df![
+            "feature1_missing" => [1u8, 0],
+            "feature2_missing" => [0u8, 0],
+            "label_missing" => [0u8, 0]
+        ]
+        .unwrap();
+        assert_eq!(indicator_df, expected);
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let indicator = MissingIndicator::new();
+        assert!(matches!(
+            indicator.transform(&create_missing_df()),
+            Err(ImputationError::NotFittedError)
+        ));
+    }
+
+    // Test combination with an imputer
+    #[test]
+    fn test_with_missing_indicator() {
+        let df = create_missing_df().drop("label").unwrap();
+        let imputer = SimpleImputer::new(ImputationStrategy::Mean).with_nan_as_missing(true);
+        let indicator = MissingIndicator::new().with_nan_as_missing(true);
+        let mut combined = WithMissingIndicator::new(imputer, indicator);
+
+        let combined_df = combined.fit_transform(&df).unwrap();
+
+        let expected = df![
+            "feature1" => [1.0f64, 2.0, 3.0],
+            "feature2" => [4.0f64, 5.0, 6.0],
+            "complete" => [7.0f64, 8.0, 9.0],
+            "feature1_missing" => [false, true, false],
+            "feature2_missing" => [false, true, false]
+        ]
+        .unwrap();
+        assert_eq!(combined_df, expected);
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut indicator = MissingIndicator::new();
+        indicator.fit(&create_missing_df()).unwrap();
+
+        indicator.reset();
+        assert!(indicator.indicator_columns().is_none());
+    }
+}
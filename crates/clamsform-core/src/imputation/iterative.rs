@@ -0,0 +1,436 @@
+use ndarray::{
+    Array1,
+    Array2,
+    Axis,
+};
+use polars::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use super::error::*;
+use super::ridge::RidgeRegression;
+use super::simple::*;
+use super::traits::*;
+use crate::validation::error::*;
+
+/// The order in which features with missing values are imputed within each iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImputationOrder {
+    /// From the feature with the fewest missing values to the one with the most.
+    Ascending,
+    /// From the feature with the most missing values to the one with the fewest.
+    Descending,
+    /// From left to right, in column order.
+    Roman,
+    /// A random order, drawn once per iteration from the seeded generator.
+    Random,
+}
+
+pub struct IterativeImputer {
+    estimator: Box<dyn RegressionEstimator>,
+    max_iter: usize,
+    tol: f64,
+    order: ImputationOrder,
+    seed: u64,
+    infinite_as_missing: bool,
+    initial_imputer: SimpleImputer,
+    fitted_estimators: Vec<(usize, Box<dyn RegressionEstimator>)>,
+    n_iter: Option<usize>,
+}
+
+impl Default for IterativeImputer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IterativeImputer {
+    pub fn new() -> Self {
+        IterativeImputer {
+            estimator: Box::new(RidgeRegression::default()),
+            max_iter: 10,
+            tol: 1e-3,
+            order: ImputationOrder::Ascending,
+            seed: 0,
+            infinite_as_missing: false,
+            initial_imputer: SimpleImputer::new(ImputationStrategy::Mean),
+            fitted_estimators: Vec::new(),
+            n_iter: None,
+        }
+    }
+
+    /// Sets the estimator used to model each feature from the others.
+    pub fn with_estimator(mut self, estimator: Box<dyn RegressionEstimator>) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    /// Sets the maximum number of imputation rounds over all features.
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the tolerance on the largest change between rounds, relative to the largest observed magnitude.
+    pub fn with_tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn with_order(mut self, order: ImputationOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the seed of the random generator, used by `ImputationOrder::Random`.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Treats positive and negative infinite values as missing, in addition to nulls and `NaN`.
+    pub fn with_infinite_as_missing(mut self, infinite_as_missing: bool) -> Self {
+        self.infinite_as_missing = infinite_as_missing;
+        self
+    }
+
+    /// The initial fill values used before the first round.
+    pub fn fill_values(&self) -> Option<&DataFrame> {
+        self.initial_imputer.fill_values()
+    }
+
+    /// The number of rounds performed during fitting.
+    pub fn n_iter(&self) -> Option<usize> {
+        self.n_iter
+    }
+
+    /// Returns the initially imputed matrix of the fitted columns and its missing-value mask.
+    fn initial_matrix(
+        &self,
+        df: &DataFrame,
+    ) -> Result<(Array2<f64>, Array2<bool>), ImputationError> {
+        let fill_values = self.fill_values().ok_or(ImputationError::NotFittedError)?;
+        validate_columns_present(df, &fill_values.get_column_names_str())?;
+
+        let names = fill_values.get_column_names_owned();
+        let masked = df
+            .clone()
+            .lazy()
+            .select(
+                names
+                    .iter()
+                    .map(|name| {
+                        mask_missing(col(name.clone()), true, self.infinite_as_missing)
+                            .cast(DataType::Float64)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .collect()?
+            .to_ndarray::<Float64Type>(IndexOrder::C)?;
+        let mask = masked.mapv(f64::is_nan);
+
+        let imputed = self
+            .initial_imputer
+            .transform(&df.select(names)?)?
+            .to_ndarray::<Float64Type>(IndexOrder::C)?;
+
+        Ok((imputed, mask))
+    }
+
+    /// Returns the features with missing values, in imputation order.
+    fn feature_order(&self, mask: &Array2<bool>, rng: &mut StdRng) -> Vec<usize> {
+        let missing_counts: Vec<usize> = mask
+            .axis_iter(Axis(1))
+            .map(|column| column.iter().filter(|missing| **missing).count())
+            .collect();
+        let mut features: Vec<usize> = (0..missing_counts.len())
+            .filter(|&j| missing_counts[j] > 0)
+            .collect();
+
+        match self.order {
+            ImputationOrder::Ascending => features.sort_by_key(|&j| missing_counts[j]),
+            ImputationOrder::Descending => {
+                features.sort_by_key(|&j| std::cmp::Reverse(missing_counts[j]))
+            },
+            ImputationOrder::Roman => {},
+            ImputationOrder::Random => features.shuffle(rng),
+        }
+
+        features
+    }
+
+    /// Updates the missing entries of feature `j` with the predictions of a fitted estimator.
+    fn impute_feature(
+        imputed: &mut Array2<f64>,
+        mask: &Array2<bool>,
+        j: usize,
+        estimator: &dyn RegressionEstimator,
+    ) -> Result<(), ImputationError> {
+        let missing_rows: Vec<usize> = (0..imputed.nrows()).filter(|&i| mask[[i, j]]).collect();
+        if missing_rows.is_empty() {
+            return Ok(());
+        }
+
+        let predictors = other_features(imputed, j);
+        let predictions = estimator.predict(predictors.select(Axis(0), &missing_rows).view())?;
+        for (i, prediction) in missing_rows.into_iter().zip(predictions) {
+            imputed[[i, j]] = prediction;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the matrix without column `j`.
+fn other_features(matrix: &Array2<f64>, j: usize) -> Array2<f64> {
+    let others: Vec<usize> = (0..matrix.ncols()).filter(|&k| k != j).collect();
+    matrix.select(Axis(1), &others)
+}
+
+impl Imputer for IterativeImputer {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), ImputationError> {
+        if self.max_iter == 0 {
+            return Err(ImputationError::InvalidParameterError(
+                "max_iter must be at least 1".to_string(),
+            ));
+        }
+
+        validate_not_empty_df(df)?;
+        validate_numeric_columns(df)?;
+
+        self.reset();
+        self.initial_imputer = SimpleImputer::new(ImputationStrategy::Mean)
+            .with_nan_as_missing(true)
+            .with_infinite_as_missing(self.infinite_as_missing);
+        self.initial_imputer.fit(df)?;
+
+        let (mut imputed, mask) = self.initial_matrix(df)?;
+        let observed_scale = imputed
+            .iter()
+            .zip(mask.iter())
+            .filter(|(_, missing)| !**missing)
+            .fold(0.0f64, |max, (val, _)| max.max(val.abs()));
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut n_iter = 0;
+
+        for _ in 0..self.max_iter {
+            let previous = imputed.clone();
+            n_iter += 1;
+
+            for j in self.feature_order(&mask, &mut rng) {
+                let observed_rows: Vec<usize> =
+                    (0..imputed.nrows()).filter(|&i| !mask[[i, j]]).collect();
+                let predictors = other_features(&imputed, j).select(Axis(0), &observed_rows);
+                let target: Array1<f64> = imputed.column(j).select(Axis(0), &observed_rows);
+
+                let mut estimator = self.estimator.boxed_clone();
+                estimator.fit(predictors.view(), target.view())?;
+                Self::impute_feature(&mut imputed, &mask, j, estimator.as_ref())?;
+                self.fitted_estimators.push((j, estimator));
+            }
+
+            let change = (&imputed - &previous)
+                .iter()
+                .fold(0.0f64, |max, val| max.max(val.abs()));
+            if change <= self.tol * observed_scale {
+                break;
+            }
+        }
+
+        self.n_iter = Some(n_iter);
+
+        Ok(())
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, ImputationError> {
+        let (mut imputed, mask) = self.initial_matrix(df)?;
+
+        for (j, estimator) in &self.fitted_estimators {
+            Self::impute_feature(&mut imputed, &mask, *j, estimator.as_ref())?;
+        }
+
+        let mut imputed_df = df.clone();
+        let names = self
+            .fill_values()
+            .ok_or(ImputationError::NotFittedError)?
+            .get_column_names_owned();
+        for (j, name) in names.into_iter().enumerate() {
+            let dtype = df.column(&name)?.dtype().clone();
+            imputed_df.with_column(Series::new(name, imputed.column(j).to_vec()).cast(&dtype)?)?;
+        }
+
+        Ok(imputed_df)
+    }
+
+    fn reset(&mut self) {
+        self.initial_imputer.reset();
+        self.fitted_estimators.clear();
+        self.n_iter = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use ndarray::{
+        ArrayView1,
+        ArrayView2,
+    };
+
+    use super::*;
+
+    fn create_missing_df() -> DataFrame {
+        df![
+            "x" => [Some(1.0f64), Some(2.0), Some(3.0), None, Some(5.0), Some(6.0)],
+            "y" => [Some(3.0f64), Some(5.0), None, Some(9.0), Some(11.0), Some(f64::NAN)]
+        ]
+        .unwrap()
+    }
+
+    fn value(df: &DataFrame, name: &str, row: usize) -> f64 {
+        df.column(name)
+            .unwrap()
+            .get(row)
+            .unwrap()
+            .extract::<f64>()
+            .unwrap()
+    }
+
+    /// Predicts the mean of the targets, ignoring the features.
+    struct MeanEstimator {
+        mean: Option<f64>,
+    }
+
+    impl RegressionEstimator for MeanEstimator {
+        fn fit(&mut self, _x: ArrayView2<f64>, y: ArrayView1<f64>) -> Result<(), ImputationError> {
+            self.mean = y.mean();
+            Ok(())
+        }
+
+        fn predict(&self, x: ArrayView2<f64>) -> Result<Array1<f64>, ImputationError> {
+            let mean = self.mean.ok_or(ImputationError::NotFittedError)?;
+            Ok(Array1::from_elem(x.nrows(), mean))
+        }
+
+        fn boxed_clone(&self) -> Box<dyn RegressionEstimator> {
+            Box::new(MeanEstimator { mean: None })
+        }
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let imputer = IterativeImputer::new();
+        assert_eq!(imputer.max_iter, 10);
+        assert_eq!(imputer.order, ImputationOrder::Ascending);
+        assert!(imputer.fill_values().is_none());
+        assert!(imputer.n_iter().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_invalid_max_iter() {
+        let mut imputer = IterativeImputer::new().with_max_iter(0);
+        assert!(matches!(
+            imputer.fit(&create_missing_df()),
+            Err(ImputationError::InvalidParameterError(_))
+        ));
+    }
+
+    #[test]
+    fn test_fit_converges() {
+        let mut imputer = IterativeImputer::new()
+            .with_estimator(Box::new(RidgeRegression::new(1e-6)))
+            .with_max_iter(50);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        assert!(imputer.n_iter().unwrap() < 50);
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_recovers_linear_relationship() {
+        let mut imputer = IterativeImputer::new()
+            .with_estimator(Box::new(RidgeRegression::new(1e-6)))
+            .with_max_iter(50)
+            .with_tol(1e-8);
+        let imputed_df = imputer.fit_transform(&create_missing_df()).unwrap();
+
+        // y = 2x + 1 on the complete rows.
+        assert_abs_diff_eq!(value(&imputed_df, "y", 2), 7.0, epsilon = 1e-3);
+        assert_abs_diff_eq!(value(&imputed_df, "x", 3), 4.0, epsilon = 1e-3);
+        assert_abs_diff_eq!(value(&imputed_df, "y", 5), 13.0, epsilon = 1e-3);
+        assert!(validate_dataframe(&imputed_df).is_ok());
+    }
+
+    #[test]
+    fn test_transform_new_data() {
+        let mut imputer = IterativeImputer::new()
+            .with_estimator(Box::new(RidgeRegression::new(1e-6)))
+            .with_max_iter(50)
+            .with_tol(1e-8);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        let df = df![
+            "x" => [Some(10.0f64)],
+            "y" => [None::<f64>]
+        ]
+        .unwrap();
+        let imputed_df = imputer.transform(&df).unwrap();
+        assert_abs_diff_eq!(value(&imputed_df, "y", 0), 21.0, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_transform_custom_estimator() {
+        let mut imputer =
+            IterativeImputer::new().with_estimator(Box::new(MeanEstimator { mean: None }));
+        let imputed_df = imputer.fit_transform(&create_missing_df()).unwrap();
+
+        assert_abs_diff_eq!(value(&imputed_df, "y", 2), 7.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(value(&imputed_df, "x", 3), 3.4, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transform_seed_is_reproducible() {
+        let df = df![
+            "a" => [Some(1.0f64), None, Some(3.0), Some(4.0), Some(2.0)],
+            "b" => [Some(2.0f64), Some(1.0), None, Some(5.0), Some(3.0)],
+            "c" => [None, Some(4.0f64), Some(1.0), Some(2.0), Some(6.0)]
+        ]
+        .unwrap();
+
+        let impute = |seed: u64| {
+            IterativeImputer::new()
+                .with_order(ImputationOrder::Random)
+                .with_max_iter(3)
+                .with_seed(seed)
+                .fit_transform(&df)
+                .unwrap()
+        };
+
+        assert_eq!(impute(42), impute(42));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let imputer = IterativeImputer::new();
+        assert!(matches!(
+            imputer.transform(&create_missing_df()),
+            Err(ImputationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut imputer = IterativeImputer::new();
+        imputer.fit(&create_missing_df()).unwrap();
+
+        imputer.reset();
+        assert!(imputer.fill_values().is_none());
+        assert!(imputer.fitted_estimators.is_empty());
+        assert!(imputer.n_iter().is_none());
+    }
+}
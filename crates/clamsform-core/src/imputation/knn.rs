@@ -0,0 +1,382 @@
+use ndarray::{
+    Array2,
+    ArrayView1,
+    Axis,
+};
+use polars::prelude::*;
+use rayon::prelude::*;
+
+use super::error::*;
+use super::simple::mask_missing;
+use super::traits::Imputer;
+use crate::validation::error::*;
+
+/// How the neighbors' values are combined into a fill value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnnWeighting {
+    /// Every neighbor contributes equally.
+    Uniform,
+    /// Neighbors contribute in proportion to the inverse of their distance.
+    Distance,
+}
+
+/// Computes the NaN-aware Euclidean distance between two rows.
+///
+/// Only coordinates present in both rows contribute, and the squared distance is scaled up
+/// by the ratio of total to present coordinates. Returns `None` when no coordinate is shared.
+pub(crate) fn nan_euclidean_distance(a: ArrayView1<f64>, b: ArrayView1<f64>) -> Option<f64> {
+    let (squared_sum, present) = a
+        .iter()
+        .zip(b.iter())
+        .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+        .fold((0.0, 0usize), |(sum, count), (x, y)| {
+            (sum + (x - y).powi(2), count + 1)
+        });
+
+    if present == 0 {
+        return None;
+    }
+
+    Some((squared_sum * a.len() as f64 / present as f64).sqrt())
+}
+
+pub struct KnnImputer {
+    n_neighbors: usize,
+    weighting: KnnWeighting,
+    infinite_as_missing: bool,
+    fit_data: Option<DataFrame>,
+}
+
+impl Default for KnnImputer {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+impl KnnImputer {
+    pub fn new(n_neighbors: usize) -> Self {
+        KnnImputer {
+            n_neighbors,
+            weighting: KnnWeighting::Uniform,
+            infinite_as_missing: false,
+            fit_data: None,
+        }
+    }
+
+    pub fn with_weighting(mut self, weighting: KnnWeighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
+    /// Treats positive and negative infinite values as missing, in addition to nulls and `NaN`.
+    pub fn with_infinite_as_missing(mut self, infinite_as_missing: bool) -> Self {
+        self.infinite_as_missing = infinite_as_missing;
+        self
+    }
+
+    pub fn n_neighbors(&self) -> usize {
+        self.n_neighbors
+    }
+
+    pub fn weighting(&self) -> KnnWeighting {
+        self.weighting
+    }
+
+    /// The training rows used as neighbors, with missing values stored as nulls.
+    pub fn fit_data(&self) -> Option<&DataFrame> {
+        self.fit_data.as_ref()
+    }
+
+    /// Converts the named columns to a matrix with every missing value stored as `NaN`.
+    fn to_array(
+        &self,
+        df: &DataFrame,
+        names: &[PlSmallStr],
+    ) -> Result<Array2<f64>, ImputationError> {
+        let masked = df
+            .clone()
+            .lazy()
+            .select(
+                names
+                    .iter()
+                    .map(|name| {
+                        mask_missing(col(name.clone()), false, self.infinite_as_missing)
+                            .cast(DataType::Float64)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .collect()?;
+
+        Ok(masked.to_ndarray::<Float64Type>(IndexOrder::C)?)
+    }
+
+    /// Fills the missing entries of a single row from its nearest training rows.
+    fn impute_row(&self, row: ArrayView1<f64>, train: &Array2<f64>, fallback: &[f64]) -> Vec<f64> {
+        let mut imputed = row.to_vec();
+        if !row.iter().any(|val| val.is_nan()) {
+            return imputed;
+        }
+
+        let distances: Vec<Option<f64>> = train
+            .axis_iter(Axis(0))
+            .map(|train_row| nan_euclidean_distance(row, train_row))
+            .collect();
+
+        for (j, value) in imputed.iter_mut().enumerate() {
+            if !value.is_nan() {
+                continue;
+            }
+
+            let mut donors: Vec<(f64, f64)> = distances
+                .iter()
+                .zip(train.column(j).iter())
+                .filter_map(|(distance, donor)| match distance {
+                    Some(distance) if !donor.is_nan() => Some((*distance, *donor)),
+                    _ => None,
+                })
+                .collect();
+
+            if donors.is_empty() {
+                *value = fallback[j];
+                continue;
+            }
+
+            donors.sort_by(|a, b| a.0.total_cmp(&b.0));
+            donors.truncate(self.n_neighbors);
+
+            *value = match self.weighting {
+                KnnWeighting::Uniform => {
+                    donors.iter().map(|(_, donor)| donor).sum::<f64>() / donors.len() as f64
+                },
+                KnnWeighting::Distance if donors.iter().any(|(distance, _)| *distance == 0.0) => {
+                    let exact: Vec<f64> = donors
+                        .iter()
+                        .filter(|(distance, _)| *distance == 0.0)
+                        .map(|(_, donor)| *donor)
+                        .collect();
+                    exact.iter().sum::<f64>() / exact.len() as f64
+                },
+                KnnWeighting::Distance => {
+                    let (weighted_sum, weight_total) =
+                        donors
+                            .iter()
+                            .fold((0.0, 0.0), |(sum, total), (distance, donor)| {
+                                (sum + donor / distance, total + 1.0 / distance)
+                            });
+                    weighted_sum / weight_total
+                },
+            };
+        }
+
+        imputed
+    }
+}
+
+impl Imputer for KnnImputer {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), ImputationError> {
+        if self.n_neighbors == 0 {
+            return Err(ImputationError::InvalidParameterError(
+                "n_neighbors must be at least 1".to_string(),
+            ));
+        }
+
+        validate_not_empty_df(df)?;
+        validate_numeric_columns(df)?;
+
+        let fit_data = df
+            .clone()
+            .lazy()
+            .select([mask_missing(all(), true, self.infinite_as_missing)])
+            .collect()?;
+
+        let all_missing_cols: Vec<String> = fit_data
+            .get_columns()
+            .iter()
+            .filter(|col| col.null_count() == col.len())
+            .map(|col| col.name().to_string())
+            .collect();
+
+        if !all_missing_cols.is_empty() {
+            return Err(ImputationError::AllMissingError(
+                all_missing_cols.join(", "),
+            ));
+        }
+
+        self.fit_data = Some(fit_data);
+
+        Ok(())
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, ImputationError> {
+        let fit_data = self
+            .fit_data
+            .as_ref()
+            .ok_or(ImputationError::NotFittedError)?;
+        validate_columns_present(df, &fit_data.get_column_names_str())?;
+
+        let names: Vec<PlSmallStr> = fit_data.get_column_names_owned();
+        let train = self.to_array(fit_data, &names)?;
+        let data = self.to_array(df, &names)?;
+
+        let fallback: Vec<f64> = train
+            .axis_iter(Axis(1))
+            .map(|column| {
+                let observed: Vec<f64> = column.iter().copied().filter(|v| !v.is_nan()).collect();
+                observed.iter().sum::<f64>() / observed.len() as f64
+            })
+            .collect();
+
+        let imputed_rows: Vec<Vec<f64>> = (0..data.nrows())
+            .into_par_iter()
+            .map(|i| self.impute_row(data.row(i), &train, &fallback))
+            .collect();
+
+        let mut imputed_df = df.clone();
+        for (j, name) in names.iter().enumerate() {
+            let values: Vec<f64> = imputed_rows.iter().map(|row| row[j]).collect();
+            let dtype = df.column(name)?.dtype().clone();
+            imputed_df.with_column(Series::new(name.clone(), values).cast(&dtype)?)?;
+        }
+
+        Ok(imputed_df)
+    }
+
+    fn reset(&mut self) {
+        self.fit_data = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    use super::*;
+
+    fn create_missing_df() -> DataFrame {
+        df![
+            "feature1" => [Some(1.0f64), Some(3.0), None, Some(8.0)],
+            "feature2" => [2.0f64, 4.0, 6.0, 8.0],
+            "feature3" => [Some(f64::NAN), Some(3.0), Some(5.0), Some(7.0)]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let imputer = KnnImputer::new(3);
+        assert_eq!(imputer.n_neighbors(), 3);
+        assert_eq!(imputer.weighting(), KnnWeighting::Uniform);
+        assert!(imputer.fit_data().is_none());
+    }
+
+    #[test]
+    fn test_default_constructor() {
+        let imputer = KnnImputer::default();
+        assert_eq!(imputer.n_neighbors(), 5);
+        assert!(imputer.fit_data().is_none());
+    }
+
+    // Test distance computation
+    #[test]
+    fn test_nan_euclidean_distance() {
+        let a = array![1.0, f64::NAN, 3.0];
+        let b = array![4.0, 2.0, 7.0];
+        let distance = nan_euclidean_distance(a.view(), b.view()).unwrap();
+        assert_abs_diff_eq!(distance, (25.0f64 * 3.0 / 2.0).sqrt());
+
+        let c = array![f64::NAN, 1.0, f64::NAN];
+        assert!(nan_euclidean_distance(a.view(), c.view()).is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_invalid_neighbors() {
+        let mut imputer = KnnImputer::new(0);
+        assert!(matches!(
+            imputer.fit(&create_missing_df()),
+            Err(ImputationError::InvalidParameterError(_))
+        ));
+    }
+
+    #[test]
+    fn test_fit_stores_masked_data() {
+        let mut imputer = KnnImputer::new(2);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        let fit_data = imputer.fit_data().unwrap();
+        assert_eq!(fit_data.column("feature3").unwrap().null_count(), 1);
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_uniform() {
+        let mut imputer = KnnImputer::new(2);
+        let imputed_df = imputer.fit_transform(&create_missing_df()).unwrap();
+
+        let expected = df![
+            "feature1" => [1.0f64, 3.0, 5.5, 8.0],
+            "feature2" => [2.0f64, 4.0, 6.0, 8.0],
+            "feature3" => [4.0f64, 3.0, 5.0, 7.0]
+        ]
+        .unwrap();
+        assert_eq!(imputed_df, expected);
+        assert!(validate_dataframe(&imputed_df).is_ok());
+    }
+
+    #[test]
+    fn test_transform_distance() {
+        let mut imputer = KnnImputer::new(2).with_weighting(KnnWeighting::Distance);
+        let imputed_df = imputer.fit_transform(&create_missing_df()).unwrap();
+
+        // Row 0 is at distance sqrt(12) from row 1 and sqrt(48) from row 2.
+        let (d1, d2) = (12.0f64.sqrt(), 48.0f64.sqrt());
+        let expected = (3.0 / d1 + 5.0 / d2) / (1.0 / d1 + 1.0 / d2);
+        let actual = imputed_df
+            .column("feature3")
+            .unwrap()
+            .get(0)
+            .unwrap()
+            .extract::<f64>()
+            .unwrap();
+        assert_abs_diff_eq!(actual, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transform_new_data() {
+        let mut imputer = KnnImputer::new(1);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        let df = df![
+            "feature1" => [Some(7.5f64)],
+            "feature2" => [None::<f64>],
+            "feature3" => [Some(7.0f64)]
+        ]
+        .unwrap();
+        let imputed_df = imputer.transform(&df).unwrap();
+        assert_eq!(
+            imputed_df.column("feature2").unwrap().get(0).unwrap(),
+            AnyValue::Float64(8.0)
+        );
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let imputer = KnnImputer::default();
+        assert!(matches!(
+            imputer.transform(&create_missing_df()),
+            Err(ImputationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut imputer = KnnImputer::new(2);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        imputer.reset();
+        assert!(imputer.fit_data().is_none());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod error;
+pub mod indicator;
+pub mod iterative;
+pub mod knn;
+pub mod ridge;
+pub mod simple;
+pub mod traits;
@@ -0,0 +1,189 @@
+use ndarray::{
+    Array1,
+    Array2,
+    ArrayView1,
+    ArrayView2,
+    Axis,
+};
+
+use super::error::*;
+use super::traits::RegressionEstimator;
+
+/// Solves the linear system `a * x = b` by Gaussian elimination with partial pivoting.
+///
+/// Returns `None` if the system is singular.
+pub(crate) fn solve_linear_system(mut a: Array2<f64>, mut b: Array1<f64>) -> Option<Array1<f64>> {
+    let n = b.len();
+
+    for pivot in 0..n {
+        let max_row =
+            (pivot..n).max_by(|&i, &j| a[[i, pivot]].abs().total_cmp(&a[[j, pivot]].abs()))?;
+        if a[[max_row, pivot]].abs() < f64::EPSILON {
+            return None;
+        }
+
+        if max_row != pivot {
+            for k in 0..n {
+                a.swap([pivot, k], [max_row, k]);
+            }
+            b.swap(pivot, max_row);
+        }
+
+        for row in pivot + 1..n {
+            let factor = a[[row, pivot]] / a[[pivot, pivot]];
+            for k in pivot..n {
+                a[[row, k]] -= factor * a[[pivot, k]];
+            }
+            b[row] -= factor * b[pivot];
+        }
+    }
+
+    let mut x = Array1::<f64>::zeros(n);
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| a[[row, k]] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[[row, row]];
+    }
+
+    Some(x)
+}
+
+/// Linear least squares with an L2 penalty on the coefficients.
+///
+/// The intercept is fitted by centering the data and is not penalized.
+#[derive(Debug, Clone)]
+pub struct RidgeRegression {
+    alpha: f64,
+    coefficients: Option<Array1<f64>>,
+    intercept: Option<f64>,
+}
+
+impl Default for RidgeRegression {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl RidgeRegression {
+    pub fn new(alpha: f64) -> Self {
+        RidgeRegression {
+            alpha,
+            coefficients: None,
+            intercept: None,
+        }
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    pub fn coefficients(&self) -> Option<&Array1<f64>> {
+        self.coefficients.as_ref()
+    }
+
+    pub fn intercept(&self) -> Option<f64> {
+        self.intercept
+    }
+}
+
+impl RegressionEstimator for RidgeRegression {
+    fn fit(&mut self, x: ArrayView2<f64>, y: ArrayView1<f64>) -> Result<(), ImputationError> {
+        if x.nrows() == 0 || x.nrows() != y.len() {
+            return Err(ImputationError::EstimatorError(format!(
+                "cannot fit {} rows of features to {} targets",
+                x.nrows(),
+                y.len()
+            )));
+        }
+
+        let x_mean = x.mean_axis(Axis(0)).unwrap();
+        let y_mean = y.mean().unwrap();
+        let x_centered = &x - &x_mean;
+        let y_centered = &y - y_mean;
+
+        let mut gram = x_centered.t().dot(&x_centered);
+        gram.diag_mut().mapv_inplace(|val| val + self.alpha);
+        let moments = x_centered.t().dot(&y_centered);
+
+        let coefficients = solve_linear_system(gram, moments).ok_or_else(|| {
+            ImputationError::EstimatorError(
+                "the ridge system is singular, consider increasing alpha".to_string(),
+            )
+        })?;
+
+        self.intercept = Some(y_mean - x_mean.dot(&coefficients));
+        self.coefficients = Some(coefficients);
+
+        Ok(())
+    }
+
+    fn predict(&self, x: ArrayView2<f64>) -> Result<Array1<f64>, ImputationError> {
+        let (Some(coefficients), Some(intercept)) = (&self.coefficients, self.intercept) else {
+            return Err(ImputationError::NotFittedError);
+        };
+
+        Ok(x.dot(coefficients) + intercept)
+    }
+
+    fn boxed_clone(&self) -> Box<dyn RegressionEstimator> {
+        Box::new(RidgeRegression::new(self.alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    use super::*;
+
+    #[test]
+    fn test_solve_linear_system() {
+        let a = array![[0.0, 2.0], [3.0, 1.0]];
+        let b = array![4.0, 5.0];
+        let x = solve_linear_system(a, b).unwrap();
+        assert_abs_diff_eq!(x, array![1.0, 2.0], epsilon = 1e-12);
+
+        let singular = array![[1.0, 2.0], [2.0, 4.0]];
+        assert!(solve_linear_system(singular, array![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_fit_predict() {
+        let x = array![[1.0, 0.0], [2.0, 1.0], [3.0, 0.0], [4.0, 1.0]];
+        let y = array![3.0, 8.0, 7.0, 12.0];
+
+        let mut ridge = RidgeRegression::new(0.0);
+        ridge.fit(x.view(), y.view()).unwrap();
+
+        assert_abs_diff_eq!(
+            ridge.coefficients().unwrap(),
+            &array![2.0, 3.0],
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(ridge.intercept().unwrap(), 1.0, epsilon = 1e-10);
+
+        let predictions = ridge.predict(array![[5.0, 1.0]].view()).unwrap();
+        assert_abs_diff_eq!(predictions[0], 14.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_penalty_shrinks_coefficients() {
+        let x = array![[1.0], [2.0], [3.0], [4.0]];
+        let y = array![2.0, 4.0, 6.0, 8.0];
+
+        let mut ridge = RidgeRegression::new(5.0);
+        ridge.fit(x.view(), y.view()).unwrap();
+
+        // The centered sum of squares of x is 5, so the slope is 2 * 5 / (5 + 5).
+        assert_abs_diff_eq!(ridge.coefficients().unwrap()[0], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_predict_not_fitted() {
+        let ridge = RidgeRegression::default();
+        assert!(matches!(
+            ridge.predict(array![[1.0]].view()),
+            Err(ImputationError::NotFittedError)
+        ));
+    }
+}
@@ -0,0 +1,323 @@
+use polars::prelude::*;
+
+use super::error::*;
+use super::traits::Imputer;
+use crate::validation::error::*;
+
+/// The statistic used to fill missing values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImputationStrategy {
+    Mean,
+    Median,
+    MostFrequent,
+    Constant(f64),
+}
+
+/// Replaces the values treated as missing with nulls, so that they can be filled uniformly.
+pub(crate) fn mask_missing(expr: Expr, nan_as_missing: bool, infinite_as_missing: bool) -> Expr {
+    let mut masked = expr;
+
+    if nan_as_missing {
+        masked = masked.clone().fill_nan(lit(NULL));
+    }
+
+    if infinite_as_missing {
+        masked = when(masked.clone().is_infinite())
+            .then(lit(NULL))
+            .otherwise(masked);
+    }
+
+    masked
+}
+
+pub struct SimpleImputer {
+    strategy: ImputationStrategy,
+    nan_as_missing: bool,
+    infinite_as_missing: bool,
+    fill_values: Option<DataFrame>,
+}
+
+impl Default for SimpleImputer {
+    fn default() -> Self {
+        Self::new(ImputationStrategy::Mean)
+    }
+}
+
+impl SimpleImputer {
+    pub fn new(strategy: ImputationStrategy) -> Self {
+        SimpleImputer {
+            strategy,
+            nan_as_missing: false,
+            infinite_as_missing: false,
+            fill_values: None,
+        }
+    }
+
+    /// Treats `NaN` values as missing, both when learning and when filling.
+    pub fn with_nan_as_missing(mut self, nan_as_missing: bool) -> Self {
+        self.nan_as_missing = nan_as_missing;
+        self
+    }
+
+    /// Treats positive and negative infinite values as missing, both when learning and when filling.
+    pub fn with_infinite_as_missing(mut self, infinite_as_missing: bool) -> Self {
+        self.infinite_as_missing = infinite_as_missing;
+        self
+    }
+
+    pub fn strategy(&self) -> ImputationStrategy {
+        self.strategy
+    }
+
+    pub fn fill_values(&self) -> Option<&DataFrame> {
+        self.fill_values.as_ref()
+    }
+
+    fn masked(&self, name: &str) -> Expr {
+        mask_missing(col(name), self.nan_as_missing, self.infinite_as_missing)
+    }
+}
+
+impl Imputer for SimpleImputer {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), ImputationError> {
+        validate_not_empty_df(df)?;
+        validate_numeric_columns(df)?;
+
+        let fill_exprs: Vec<Expr> = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| {
+                let fill = match self.strategy {
+                    ImputationStrategy::Mean => self.masked(name).mean(),
+                    ImputationStrategy::Median => self.masked(name).median(),
+                    ImputationStrategy::MostFrequent => self.masked(name).drop_nulls().mode().min(),
+                    ImputationStrategy::Constant(value) => lit(value),
+                };
+                fill.cast(DataType::Float64).alias(name.clone())
+            })
+            .collect();
+
+        let fill_values = df.clone().lazy().select(fill_exprs).collect()?;
+
+        let all_missing_cols: Vec<String> = fill_values
+            .get_columns()
+            .iter()
+            .filter(|col| col.null_count() > 0)
+            .map(|col| col.name().to_string())
+            .collect();
+
+        if !all_missing_cols.is_empty() {
+            return Err(ImputationError::AllMissingError(
+                all_missing_cols.join(", "),
+            ));
+        }
+
+        self.fill_values = Some(fill_values);
+
+        Ok(())
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, ImputationError> {
+        let fill_values = self
+            .fill_values
+            .as_ref()
+            .ok_or(ImputationError::NotFittedError)?;
+        validate_columns_present(df, &fill_values.get_column_names_str())?;
+
+        let mut fill_exprs: Vec<Expr> = Vec::with_capacity(fill_values.width());
+        for fill_col in fill_values.get_columns() {
+            let name = fill_col.name();
+            let dtype = df.column(name)?.dtype().clone();
+            let fill = fill_col.get(0)?.extract::<f64>().unwrap_or(f64::NAN);
+
+            fill_exprs.push(
+                self.masked(name)
+                    .fill_null(lit(fill))
+                    .cast(dtype)
+                    .alias(name.clone()),
+            );
+        }
+
+        let imputed_df = df.clone().lazy().with_columns(fill_exprs).collect()?;
+
+        Ok(imputed_df)
+    }
+
+    fn reset(&mut self) {
+        self.fill_values = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_missing_df() -> DataFrame {
+        df![
+            "feature1" => [Some(1.0f64), None, Some(3.0), Some(f64::NAN), Some(3.0)],
+            "feature2" => [Some(10.0f32), Some(20.0), None, Some(40.0), Some(f32::INFINITY)]
+        ]
+        .unwrap()
+    }
+
+    fn create_imputer(strategy: ImputationStrategy) -> SimpleImputer {
+        SimpleImputer::new(strategy)
+            .with_nan_as_missing(true)
+            .with_infinite_as_missing(true)
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let imputer = SimpleImputer::new(ImputationStrategy::Median);
+        assert_eq!(imputer.strategy(), ImputationStrategy::Median);
+        assert!(!imputer.nan_as_missing);
+        assert!(!imputer.infinite_as_missing);
+        assert!(imputer.fill_values().is_none());
+    }
+
+    #[test]
+    fn test_default_constructor() {
+        let imputer = SimpleImputer::default();
+        assert_eq!(imputer.strategy(), ImputationStrategy::Mean);
+        assert!(imputer.fill_values().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_mean() {
+        let mut imputer = create_imputer(ImputationStrategy::Mean);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        let fill_values = imputer.fill_values().unwrap();
+        let fill = |name: &str| {
+            fill_values
+                .column(name)
+                .unwrap()
+                .get(0)
+                .unwrap()
+                .extract::<f64>()
+        };
+        assert_abs_diff_eq!(fill("feature1").unwrap(), 7.0 / 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(fill("feature2").unwrap(), 70.0 / 3.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_fit_median() {
+        let mut imputer = create_imputer(ImputationStrategy::Median);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        let expected = df![
+            "feature1" => [3.0f64],
+            "feature2" => [20.0f64]
+        ]
+        .unwrap();
+        assert_eq!(imputer.fill_values().unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_fit_most_frequent() {
+        let mut imputer = create_imputer(ImputationStrategy::MostFrequent);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        let expected = df![
+            "feature1" => [3.0f64],
+            "feature2" => [10.0f64]
+        ]
+        .unwrap();
+        assert_eq!(imputer.fill_values().unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_fit_all_missing() {
+        let df = df![
+            "feature1" => [Some(1.0f64), Some(2.0)],
+            "empty" => [None::<f64>, Some(f64::NAN)]
+        ]
+        .unwrap();
+
+        let mut imputer = create_imputer(ImputationStrategy::Mean);
+        let result = imputer.fit(&df);
+        assert!(matches!(
+            result,
+            Err(ImputationError::AllMissingError(ref cols)) if cols == "empty"
+        ));
+
+        let mut imputer = create_imputer(ImputationStrategy::Constant(0.0));
+        assert!(imputer.fit(&df).is_ok());
+    }
+
+    #[test]
+    fn test_fit_non_numeric() {
+        let df = df!["label" => ["a", "b"]].unwrap();
+        let mut imputer = SimpleImputer::default();
+        assert!(matches!(
+            imputer.fit(&df),
+            Err(ImputationError::ValidationError(
+                ValidationError::NonNumericError(_)
+            ))
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_constant() {
+        let mut imputer = create_imputer(ImputationStrategy::Constant(-1.0));
+        let imputed_df = imputer.fit_transform(&create_missing_df()).unwrap();
+
+        let expected = df![
+            "feature1" => [1.0f64, -1.0, 3.0, -1.0, 3.0],
+            "feature2" => [10.0f32, 20.0, -1.0, 40.0, -1.0]
+        ]
+        .unwrap();
+        assert_eq!(imputed_df, expected);
+        assert!(validate_dataframe(&imputed_df).is_ok());
+    }
+
+    #[test]
+    fn test_transform_keeps_nan_by_default() {
+        let mut imputer = SimpleImputer::new(ImputationStrategy::Constant(0.0));
+        let imputed_df = imputer.fit_transform(&create_missing_df()).unwrap();
+
+        assert_eq!(imputed_df.column("feature1").unwrap().null_count(), 0);
+        assert!(matches!(
+            validate_dataframe(&imputed_df),
+            Err(ValidationError::NanValuesError(_))
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let imputer = SimpleImputer::default();
+        assert!(matches!(
+            imputer.transform(&create_missing_df()),
+            Err(ImputationError::NotFittedError)
+        ));
+    }
+
+    #[test]
+    fn test_transform_missing_columns() {
+        let mut imputer = create_imputer(ImputationStrategy::Mean);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        let df = df!["feature1" => [1.0f64]].unwrap();
+        assert!(matches!(
+            imputer.transform(&df),
+            Err(ImputationError::ValidationError(
+                ValidationError::MissingColumnsError(_)
+            ))
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut imputer = create_imputer(ImputationStrategy::Mean);
+        imputer.fit(&create_missing_df()).unwrap();
+
+        imputer.reset();
+        assert!(imputer.fill_values().is_none());
+    }
+}
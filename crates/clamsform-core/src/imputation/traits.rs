@@ -0,0 +1,36 @@
+use ndarray::{
+    Array1,
+    ArrayView1,
+    ArrayView2,
+};
+use polars::prelude::DataFrame;
+
+use super::error::ImputationError;
+
+pub trait Imputer {
+    /// Learns the fill values from the dataframe
+    fn fit(&mut self, df: &DataFrame) -> Result<(), ImputationError>;
+
+    /// Fills missing values using the learned fill values
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, ImputationError>;
+
+    /// Learns the fill values and returns the imputed dataframe
+    fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, ImputationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    fn reset(&mut self);
+}
+
+pub trait RegressionEstimator: Send + Sync {
+    /// Fits the estimator to predict `y` from the rows of `x`
+    fn fit(&mut self, x: ArrayView2<f64>, y: ArrayView1<f64>) -> Result<(), ImputationError>;
+
+    /// Predicts a value for every row of `x`
+    fn predict(&self, x: ArrayView2<f64>) -> Result<Array1<f64>, ImputationError>;
+
+    /// Returns an unfitted copy of the estimator with the same configuration
+    fn boxed_clone(&self) -> Box<dyn RegressionEstimator>;
+}
@@ -1,2 +1,8 @@
+pub mod encoding;
+pub mod imputation;
+pub mod outliers;
+pub mod profiling;
 pub mod scaling;
+pub mod text;
+pub mod transformation;
 pub mod validation;
@@ -0,0 +1,26 @@
+use polars::prelude::*;
+use thiserror::Error;
+
+use crate::scaling::standardization::error::ScalingError;
+use crate::validation::error::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum OutlierError {
+    #[error(
+        "The detector has not been fitted. \
+        Call `fit` before attempting to score a DataFrame."
+    )]
+    NotFittedError,
+
+    #[error("Invalid detector parameter: {0}")]
+    InvalidParameterError(String),
+
+    #[error(transparent)]
+    ScalingError(#[from] ScalingError),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
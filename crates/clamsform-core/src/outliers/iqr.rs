@@ -0,0 +1,221 @@
+use polars::prelude::*;
+
+use super::error::*;
+use super::traits::*;
+use crate::scaling::standardization::error::validate_non_zero_denom;
+use crate::validation::error::*;
+
+/// Scores every row by how far its values lie beyond the quartiles, in units of the
+/// interquartile range (IQR).
+///
+/// A row is an outlier when one of its values lies outside the Tukey fences
+/// `[Q1 - k * IQR, Q3 + k * IQR]`, that is when its score exceeds the multiplier `k`.
+pub struct IqrDetector {
+    multiplier: f64,
+    q1: Option<DataFrame>,
+    q3: Option<DataFrame>,
+}
+
+impl Default for IqrDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IqrDetector {
+    pub fn new() -> Self {
+        IqrDetector {
+            multiplier: 1.5,
+            q1: None,
+            q3: None,
+        }
+    }
+
+    /// Sets the multiplier `k` of the IQR placing the fences. Defaults to 1.5.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// The first quartile of every fitted column.
+    pub fn q1(&self) -> Option<&DataFrame> {
+        self.q1.as_ref()
+    }
+
+    /// The third quartile of every fitted column.
+    pub fn q3(&self) -> Option<&DataFrame> {
+        self.q3.as_ref()
+    }
+}
+
+impl OutlierDetector for IqrDetector {
+    fn name(&self) -> &str {
+        "iqr"
+    }
+
+    fn fit(&mut self, df: &DataFrame) -> Result<(), OutlierError> {
+        if self.multiplier.is_nan() || self.multiplier < 0.0 {
+            return Err(OutlierError::InvalidParameterError(format!(
+                "the IQR multiplier must be non-negative, got {}",
+                self.multiplier
+            )));
+        }
+
+        validate_dataframe(df)?;
+
+        let quartile = |q: f64| all().quantile(lit(q), QuantileMethod::Linear);
+        let q1 = df.clone().lazy().select([quartile(0.25)]).collect()?;
+        let q3 = df.clone().lazy().select([quartile(0.75)]).collect()?;
+        let iqr = df
+            .clone()
+            .lazy()
+            .select([quartile(0.75) - quartile(0.25)])
+            .collect()?;
+        validate_non_zero_denom(&iqr, "interquartile range")?;
+
+        self.q1 = Some(q1);
+        self.q3 = Some(q3);
+
+        Ok(())
+    }
+
+    fn score(&self, df: &DataFrame) -> Result<Series, OutlierError> {
+        let (q1, q3) = self
+            .q1
+            .as_ref()
+            .zip(self.q3.as_ref())
+            .ok_or(OutlierError::NotFittedError)?;
+
+        let fitted: Vec<&str> = q1
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.as_str())
+            .collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        let column_scores = fitted
+            .iter()
+            .map(|name| {
+                let q1 = statistic(q1, name)?;
+                let q3 = statistic(q3, name)?;
+                let iqr = q3 - q1;
+                Ok(max_exprs(vec![
+                    (lit(q1) - col(*name)) / lit(iqr),
+                    (col(*name) - lit(q3)) / lit(iqr),
+                    lit(0.0),
+                ]))
+            })
+            .collect::<Result<Vec<Expr>, OutlierError>>()?;
+
+        max_column_score(df, column_scores)
+    }
+
+    fn threshold(&self) -> f64 {
+        self.multiplier
+    }
+
+    fn reset(&mut self) {
+        self.q1 = None;
+        self.q3 = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scaling::standardization::error::ScalingError;
+
+    fn create_df() -> DataFrame {
+        df![
+            "feature1" => [1.0f64, 2.0, 3.0, 4.0, 5.0],
+            "feature2" => [-20.0f64, 2.0, 3.0, 4.0, 20.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let detector = IqrDetector::new();
+        assert_eq!(detector.threshold(), 1.5);
+        assert!(detector.q1().is_none());
+        assert!(detector.q3().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut detector = IqrDetector::new();
+        detector.fit(&create_df()).unwrap();
+
+        let expected_q1 = df!["feature1" => [2.0f64], "feature2" => [2.0f64]].unwrap();
+        let expected_q3 = df!["feature1" => [4.0f64], "feature2" => [4.0f64]].unwrap();
+        assert_eq!(detector.q1().unwrap(), &expected_q1);
+        assert_eq!(detector.q3().unwrap(), &expected_q3);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut detector = IqrDetector::new().with_multiplier(-1.0);
+        assert!(matches!(
+            detector.fit(&create_df()),
+            Err(OutlierError::InvalidParameterError(_))
+        ));
+
+        let df = df!["spiky" => [1.0f64, 1.0, 1.0, 1.0, 9.0]].unwrap();
+        assert!(matches!(
+            IqrDetector::new().fit(&df),
+            Err(OutlierError::ScalingError(ScalingError::ZeroDenominatorError(_, ref cols))) if cols == "spiky"
+        ));
+    }
+
+    #[test]
+    fn test_fit_float32() {
+        let df = df!["feature1" => [1.0f32, 2.0, 3.0, 4.0, 50.0]].unwrap();
+        let mut detector = IqrDetector::new();
+        let detected = detector.fit_detect(&df).unwrap();
+
+        let mask: Vec<bool> = detected
+            .column(MASK_COLUMN)
+            .unwrap()
+            .bool()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(mask, [false, false, false, false, true]);
+    }
+
+    // Test detect method
+    #[test]
+    fn test_detect() {
+        let mut detector = IqrDetector::new();
+        let detected = detector.fit_detect(&create_df()).unwrap();
+
+        let expected = df![
+            MASK_COLUMN => [true, false, false, false, true],
+            SCORE_COLUMN => [11.0f64, 0.0, 0.0, 0.0, 8.0]
+        ]
+        .unwrap();
+        assert_eq!(detected, expected);
+    }
+
+    #[test]
+    fn test_score_not_fitted() {
+        let detector = IqrDetector::new();
+        assert!(matches!(
+            detector.score(&create_df()),
+            Err(OutlierError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut detector = IqrDetector::new();
+        detector.fit(&create_df()).unwrap();
+
+        detector.reset();
+        assert!(detector.q1().is_none());
+        assert!(detector.q3().is_none());
+    }
+}
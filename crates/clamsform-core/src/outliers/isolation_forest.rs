@@ -0,0 +1,384 @@
+use ndarray::{
+    Array2,
+    ArrayView1,
+};
+use polars::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+use rand::{
+    Rng,
+    SeedableRng,
+};
+
+use super::error::*;
+use super::traits::*;
+use crate::validation::error::*;
+
+/// The Euler–Mascheroni constant, used to approximate harmonic numbers.
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// The average path length of an unsuccessful search in a binary search tree of `n` nodes,
+/// which normalizes the path lengths of an isolation tree grown from `n` rows.
+fn average_path_length(n: usize) -> f64 {
+    match n {
+        0 | 1 => 0.0,
+        2 => 1.0,
+        n => {
+            let n = n as f64;
+            2.0 * ((n - 1.0).ln() + EULER_GAMMA) - 2.0 * (n - 1.0) / n
+        },
+    }
+}
+
+#[derive(Debug, Clone)]
+enum IsolationNode {
+    Leaf {
+        size: usize,
+    },
+    Split {
+        feature: usize,
+        threshold: f64,
+        left: usize,
+        right: usize,
+    },
+}
+
+/// A tree isolating rows by splitting a random feature at a random value, stored as a
+/// flat list of nodes whose root is the first node.
+#[derive(Debug, Clone)]
+struct IsolationTree {
+    nodes: Vec<IsolationNode>,
+}
+
+impl IsolationTree {
+    fn grow(x: &Array2<f64>, rows: Vec<usize>, max_depth: usize, rng: &mut StdRng) -> Self {
+        let mut tree = IsolationTree { nodes: Vec::new() };
+        tree.grow_node(x, rows, 0, max_depth, rng);
+        tree
+    }
+
+    /// Grows the node isolating `rows` and returns its index.
+    fn grow_node(
+        &mut self,
+        x: &Array2<f64>,
+        rows: Vec<usize>,
+        depth: usize,
+        max_depth: usize,
+        rng: &mut StdRng,
+    ) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(IsolationNode::Leaf { size: rows.len() });
+        if depth >= max_depth || rows.len() <= 1 {
+            return index;
+        }
+
+        let feature = rng.random_range(0..x.ncols());
+        let (min, max) = rows
+            .iter()
+            .map(|&row| x[[row, feature]])
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        // Rows sharing the value of the drawn feature stay together in a leaf.
+        if min >= max {
+            return index;
+        }
+
+        let threshold = rng.random_range(min..max);
+        let (left_rows, right_rows): (Vec<usize>, Vec<usize>) = rows
+            .into_iter()
+            .partition(|&row| x[[row, feature]] < threshold);
+
+        let left = self.grow_node(x, left_rows, depth + 1, max_depth, rng);
+        let right = self.grow_node(x, right_rows, depth + 1, max_depth, rng);
+        self.nodes[index] = IsolationNode::Split {
+            feature,
+            threshold,
+            left,
+            right,
+        };
+
+        index
+    }
+
+    /// The depth at which `row` is isolated, adjusted by the expected depth of the rows
+    /// left together in its leaf.
+    fn path_length(&self, row: ArrayView1<f64>) -> f64 {
+        let mut index = 0;
+        let mut depth = 0.0;
+        loop {
+            match self.nodes[index] {
+                IsolationNode::Leaf { size } => return depth + average_path_length(size),
+                IsolationNode::Split {
+                    feature,
+                    threshold,
+                    left,
+                    right,
+                } => {
+                    index = if row[feature] < threshold {
+                        left
+                    } else {
+                        right
+                    };
+                    depth += 1.0;
+                },
+            }
+        }
+    }
+}
+
+/// Scores every row by how easily random splits isolate it from the other rows.
+///
+/// Each tree is grown on a random subsample of the rows, and the score of a row is
+/// `2^(-E[h] / c(n))`, where `E[h]` is its average isolation depth and `c(n)` the average
+/// depth of a row in a tree grown from `n` rows. Scores lie between 0 and 1, and rows
+/// scoring well above 0.5 are anomalous.
+pub struct IsolationForest {
+    n_trees: usize,
+    max_samples: usize,
+    threshold: f64,
+    seed: u64,
+    input_columns: Option<Vec<String>>,
+    sample_size: usize,
+    trees: Option<Vec<IsolationTree>>,
+}
+
+impl Default for IsolationForest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IsolationForest {
+    pub fn new() -> Self {
+        IsolationForest {
+            n_trees: 100,
+            max_samples: 256,
+            threshold: 0.5,
+            seed: 0,
+            input_columns: None,
+            sample_size: 0,
+            trees: None,
+        }
+    }
+
+    /// Sets the number of trees. Defaults to 100.
+    pub fn with_n_trees(mut self, n_trees: usize) -> Self {
+        self.n_trees = n_trees;
+        self
+    }
+
+    /// Sets the number of rows drawn to grow each tree. Defaults to 256.
+    pub fn with_max_samples(mut self, max_samples: usize) -> Self {
+        self.max_samples = max_samples;
+        self
+    }
+
+    /// Sets the score above which a row is an outlier. Defaults to 0.5.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the seed of the random generator drawing the subsamples and the splits.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// The columns the forest was grown on.
+    pub fn input_columns(&self) -> Option<&[String]> {
+        self.input_columns.as_deref()
+    }
+
+    /// The number of grown trees.
+    pub fn n_trees(&self) -> usize {
+        self.trees.as_ref().map_or(0, |trees| trees.len())
+    }
+}
+
+impl OutlierDetector for IsolationForest {
+    fn name(&self) -> &str {
+        "isolation forest"
+    }
+
+    fn fit(&mut self, df: &DataFrame) -> Result<(), OutlierError> {
+        if self.n_trees == 0 || self.max_samples == 0 {
+            return Err(OutlierError::InvalidParameterError(
+                "the number of trees and of samples must be at least 1".to_string(),
+            ));
+        }
+
+        validate_dataframe(df)?;
+
+        let x = df.to_ndarray::<Float64Type>(IndexOrder::C)?;
+        let sample_size = self.max_samples.min(x.nrows());
+        let max_depth = (sample_size as f64).log2().ceil() as usize;
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let trees = (0..self.n_trees)
+            .map(|_| {
+                let rows = sample(&mut rng, x.nrows(), sample_size).into_vec();
+                IsolationTree::grow(&x, rows, max_depth, &mut rng)
+            })
+            .collect();
+
+        self.input_columns = Some(
+            df.get_column_names()
+                .into_iter()
+                .map(|name| name.to_string())
+                .collect(),
+        );
+        self.sample_size = sample_size;
+        self.trees = Some(trees);
+
+        Ok(())
+    }
+
+    fn score(&self, df: &DataFrame) -> Result<Series, OutlierError> {
+        let (columns, trees) = self
+            .input_columns
+            .as_ref()
+            .zip(self.trees.as_ref())
+            .ok_or(OutlierError::NotFittedError)?;
+
+        let fitted: Vec<&str> = columns.iter().map(|name| name.as_str()).collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        let x = df
+            .select(fitted)?
+            .to_ndarray::<Float64Type>(IndexOrder::C)?;
+        let normalizer = average_path_length(self.sample_size);
+
+        let scores: Vec<f64> = x
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let mean_depth = trees.iter().map(|tree| tree.path_length(row)).sum::<f64>()
+                    / trees.len() as f64;
+                // A forest grown from a single row cannot tell rows apart.
+                if normalizer > 0.0 {
+                    2f64.powf(-mean_depth / normalizer)
+                } else {
+                    0.5
+                }
+            })
+            .collect();
+
+        Ok(Series::new(SCORE_COLUMN.into(), scores))
+    }
+
+    fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn reset(&mut self) {
+        self.input_columns = None;
+        self.sample_size = 0;
+        self.trees = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        let mut x: Vec<f64> = (0..50).map(|i| (i % 10) as f64 * 0.1).collect();
+        let mut y: Vec<f64> = (0..50).map(|i| (i / 10) as f64 * 0.1).collect();
+        x.push(10.0);
+        y.push(-10.0);
+        df!["x" => x, "y" => y].unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let forest = IsolationForest::new();
+        assert_eq!(forest.n_trees, 100);
+        assert_eq!(forest.max_samples, 256);
+        assert_eq!(forest.threshold(), 0.5);
+        assert_eq!(forest.seed, 0);
+        assert!(forest.input_columns().is_none());
+        assert_eq!(forest.n_trees(), 0);
+    }
+
+    #[test]
+    fn test_average_path_length() {
+        assert_eq!(average_path_length(1), 0.0);
+        assert_eq!(average_path_length(2), 1.0);
+        assert_abs_diff_eq!(
+            average_path_length(256),
+            2.0 * (255f64.ln() + EULER_GAMMA) - 2.0 * 255.0 / 256.0
+        );
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut forest = IsolationForest::new().with_n_trees(10);
+        forest.fit(&create_df()).unwrap();
+
+        assert_eq!(forest.n_trees(), 10);
+        assert_eq!(forest.sample_size, 51);
+        assert_eq!(forest.input_columns().unwrap(), ["x", "y"]);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut forest = IsolationForest::new().with_n_trees(0);
+        assert!(matches!(
+            forest.fit(&create_df()),
+            Err(OutlierError::InvalidParameterError(_))
+        ));
+    }
+
+    // Test detect method
+    #[test]
+    fn test_detect() {
+        let mut forest = IsolationForest::new().with_threshold(0.6);
+        let detected = forest.fit_detect(&create_df()).unwrap();
+
+        let mask = detected.column(MASK_COLUMN).unwrap().bool().unwrap();
+        assert_eq!(mask.sum(), Some(1));
+        assert_eq!(mask.get(50), Some(true));
+
+        let score = detected.column(SCORE_COLUMN).unwrap().f64().unwrap();
+        assert!(score.into_no_null_iter().all(|s| (0.0..=1.0).contains(&s)));
+        assert_eq!(score.max(), score.get(50));
+    }
+
+    #[test]
+    fn test_score_seed_is_reproducible() {
+        let score = |seed: u64| {
+            let mut forest = IsolationForest::new().with_n_trees(20).with_seed(seed);
+            forest.fit(&create_df()).unwrap();
+            forest.score(&create_df()).unwrap()
+        };
+
+        assert_eq!(score(7), score(7));
+        assert_ne!(score(7), score(8));
+    }
+
+    #[test]
+    fn test_score_not_fitted() {
+        let forest = IsolationForest::new();
+        assert!(matches!(
+            forest.score(&create_df()),
+            Err(OutlierError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut forest = IsolationForest::new().with_n_trees(5);
+        forest.fit(&create_df()).unwrap();
+
+        forest.reset();
+        assert!(forest.input_columns().is_none());
+        assert_eq!(forest.n_trees(), 0);
+    }
+}
@@ -0,0 +1,208 @@
+use polars::prelude::*;
+
+use super::error::*;
+use super::traits::*;
+use crate::scaling::standardization::error::validate_non_zero_denom;
+use crate::validation::error::*;
+
+/// Scales the median absolute deviation (MAD) to the standard deviation of normal data.
+const MAD_SCALE: f64 = 0.6745;
+
+/// Scores every row with the largest modified z-score of its values,
+/// `0.6745 * |x - median| / MAD`.
+///
+/// The median and the MAD are barely moved by the outliers themselves, unlike the mean
+/// and the standard deviation used by the `ZScoreDetector`.
+pub struct MadDetector {
+    threshold: f64,
+    median: Option<DataFrame>,
+    mad: Option<DataFrame>,
+}
+
+impl Default for MadDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MadDetector {
+    pub fn new() -> Self {
+        MadDetector {
+            threshold: 3.5,
+            median: None,
+            mad: None,
+        }
+    }
+
+    /// Sets the modified z-score above which a row is an outlier. Defaults to 3.5.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn median(&self) -> Option<&DataFrame> {
+        self.median.as_ref()
+    }
+
+    pub fn mad(&self) -> Option<&DataFrame> {
+        self.mad.as_ref()
+    }
+}
+
+impl OutlierDetector for MadDetector {
+    fn name(&self) -> &str {
+        "mad"
+    }
+
+    fn fit(&mut self, df: &DataFrame) -> Result<(), OutlierError> {
+        validate_dataframe(df)?;
+
+        let median = df.clone().lazy().select([all().median()]).collect()?;
+        let mad = df
+            .clone()
+            .lazy()
+            .select([(all() - all().median()).abs().median()])
+            .collect()?;
+        validate_non_zero_denom(&mad, "median absolute deviation")?;
+
+        self.median = Some(median);
+        self.mad = Some(mad);
+
+        Ok(())
+    }
+
+    fn score(&self, df: &DataFrame) -> Result<Series, OutlierError> {
+        let (median, mad) = self
+            .median
+            .as_ref()
+            .zip(self.mad.as_ref())
+            .ok_or(OutlierError::NotFittedError)?;
+
+        let fitted: Vec<&str> = median
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.as_str())
+            .collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        let column_scores = fitted
+            .iter()
+            .map(|name| {
+                let median = statistic(median, name)?;
+                let mad = statistic(mad, name)?;
+                Ok(lit(MAD_SCALE) * (col(*name) - lit(median)).abs() / lit(mad))
+            })
+            .collect::<Result<Vec<Expr>, OutlierError>>()?;
+
+        max_column_score(df, column_scores)
+    }
+
+    fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn reset(&mut self) {
+        self.median = None;
+        self.mad = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::scaling::standardization::error::ScalingError;
+
+    fn create_df() -> DataFrame {
+        df![
+            "feature1" => [1.0f64, 2.0, 3.0, 4.0, 100.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let detector = MadDetector::new();
+        assert_eq!(detector.threshold(), 3.5);
+        assert!(detector.median().is_none());
+        assert!(detector.mad().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut detector = MadDetector::new();
+        detector.fit(&create_df()).unwrap();
+
+        assert_eq!(
+            detector.median().unwrap(),
+            &df!["feature1" => [3.0f64]].unwrap()
+        );
+        assert_eq!(
+            detector.mad().unwrap(),
+            &df!["feature1" => [1.0f64]].unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fit_zero_spread() {
+        let df = df!["spiky" => [1.0f64, 1.0, 1.0, 9.0]].unwrap();
+        assert!(matches!(
+            MadDetector::new().fit(&df),
+            Err(OutlierError::ScalingError(ScalingError::ZeroDenominatorError(_, ref cols))) if cols == "spiky"
+        ));
+    }
+
+    #[test]
+    fn test_fit_float32() {
+        let df = df!["feature1" => [1.0f32, 2.0, 3.0, 4.0, 100.0]].unwrap();
+        let mut detector = MadDetector::new();
+        let detected = detector.fit_detect(&df).unwrap();
+
+        let score = detected.column(SCORE_COLUMN).unwrap().f64().unwrap();
+        assert_abs_diff_eq!(score.get(0).unwrap(), 2.0 * MAD_SCALE, epsilon = 1e-5);
+        assert_abs_diff_eq!(score.get(4).unwrap(), 97.0 * MAD_SCALE, epsilon = 1e-4);
+    }
+
+    // Test detect method
+    #[test]
+    fn test_detect() {
+        let mut detector = MadDetector::new();
+        let detected = detector.fit_detect(&create_df()).unwrap();
+
+        let mask: Vec<bool> = detected
+            .column(MASK_COLUMN)
+            .unwrap()
+            .bool()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(mask, [false, false, false, false, true]);
+
+        let score = detected.column(SCORE_COLUMN).unwrap().f64().unwrap();
+        assert_abs_diff_eq!(score.get(0).unwrap(), 2.0 * MAD_SCALE);
+        assert_abs_diff_eq!(score.get(4).unwrap(), 97.0 * MAD_SCALE);
+    }
+
+    #[test]
+    fn test_score_not_fitted() {
+        let detector = MadDetector::new();
+        assert!(matches!(
+            detector.score(&create_df()),
+            Err(OutlierError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut detector = MadDetector::new();
+        detector.fit(&create_df()).unwrap();
+
+        detector.reset();
+        assert!(detector.median().is_none());
+        assert!(detector.mad().is_none());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod error;
+pub mod iqr;
+pub mod isolation_forest;
+pub mod mad;
+pub mod rule;
+pub mod traits;
+pub mod z_score;
@@ -0,0 +1,125 @@
+use polars::prelude::*;
+
+use super::error::OutlierError;
+use super::traits::*;
+use crate::validation::error::ValidationError;
+use crate::validation::rules::*;
+
+/// Runs a fitted outlier detector as a validation rule, failing with
+/// `ValidationError::OutliersError` when any row is flagged.
+///
+/// The rule is warn-level by default, so flagged rows are reported by `run_rules` without
+/// stopping the validation.
+pub struct OutlierRule<D: OutlierDetector> {
+    detector: D,
+    severity: Severity,
+}
+
+impl<D: OutlierDetector> OutlierRule<D> {
+    pub fn new(detector: D) -> Self {
+        OutlierRule {
+            detector,
+            severity: Severity::Warn,
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn detector(&self) -> &D {
+        &self.detector
+    }
+}
+
+impl<D: OutlierDetector> ValidationRule for OutlierRule<D> {
+    fn name(&self) -> &str {
+        self.detector.name()
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn check(&self, df: &DataFrame) -> Result<(), ValidationError> {
+        let detected = self.detector.detect(df).map_err(|e| match e {
+            OutlierError::ValidationError(e) => e,
+            OutlierError::PolarsError(e) => e.into(),
+            e => ValidationError::RuleCheckError(self.detector.name().to_string(), Box::new(e)),
+        })?;
+
+        let n_outliers = detected.column(MASK_COLUMN)?.bool()?.sum().unwrap_or(0) as usize;
+        if n_outliers > 0 {
+            Err(ValidationError::OutliersError(
+                self.detector.name().to_string(),
+                n_outliers,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outliers::iqr::IqrDetector;
+
+    fn create_df() -> DataFrame {
+        df!["feature1" => [1.0f64, 2.0, 3.0, 4.0, 100.0]].unwrap()
+    }
+
+    fn create_rule() -> OutlierRule<IqrDetector> {
+        let mut detector = IqrDetector::new();
+        detector.fit(&create_df()).unwrap();
+        OutlierRule::new(detector)
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let rule = create_rule();
+        assert_eq!(rule.name(), "iqr");
+        assert_eq!(rule.severity(), Severity::Warn);
+        assert!(rule.detector().q1().is_some());
+    }
+
+    // Test check method
+    #[test]
+    fn test_check() {
+        let rule = create_rule();
+        assert!(matches!(
+            rule.check(&create_df()),
+            Err(ValidationError::OutliersError(ref name, 1)) if name == "iqr"
+        ));
+
+        let df = df!["feature1" => [2.0f64, 3.0]].unwrap();
+        assert!(rule.check(&df).is_ok());
+    }
+
+    #[test]
+    fn test_check_not_fitted() {
+        let rule = OutlierRule::new(IqrDetector::new());
+        assert!(matches!(
+            rule.check(&create_df()),
+            Err(ValidationError::RuleCheckError(ref name, ref e))
+                if name == "iqr"
+                    && matches!(e.downcast_ref(), Some(OutlierError::NotFittedError))
+        ));
+    }
+
+    #[test]
+    fn test_run_rules() {
+        let warn = create_rule();
+        let warnings = run_rules(&create_df(), &[&warn]).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].rule, "iqr");
+
+        let error = create_rule().with_severity(Severity::Error);
+        assert!(matches!(
+            run_rules(&create_df(), &[&warn, &error]),
+            Err(ValidationError::OutliersError(_, 1))
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+use polars::prelude::*;
+
+use super::error::OutlierError;
+
+/// The name of the boolean column flagging the outlier rows.
+pub const MASK_COLUMN: &str = "is_outlier";
+
+/// The name of the Float64 column holding the score of every row.
+pub const SCORE_COLUMN: &str = "outlier_score";
+
+pub trait OutlierDetector {
+    /// A short name identifying the detector in validation warnings
+    fn name(&self) -> &str;
+
+    /// Learns the statistics of the dataframe
+    fn fit(&mut self, df: &DataFrame) -> Result<(), OutlierError>;
+
+    /// Scores every row of the dataframe, higher scores being more anomalous
+    fn score(&self, df: &DataFrame) -> Result<Series, OutlierError>;
+
+    /// The score above which a row is flagged as an outlier
+    fn threshold(&self) -> f64;
+
+    /// Returns the outlier mask and the score of every row of the dataframe
+    fn detect(&self, df: &DataFrame) -> Result<DataFrame, OutlierError> {
+        let score = self.score(df)?;
+        let mask = score
+            .f64()?
+            .gt(self.threshold())
+            .into_series()
+            .with_name(MASK_COLUMN.into());
+
+        Ok(DataFrame::new(vec![mask.into(), score.into()])?)
+    }
+
+    /// Learns the statistics and returns the outlier mask and the score of every row
+    fn fit_detect(&mut self, df: &DataFrame) -> Result<DataFrame, OutlierError> {
+        self.fit(df)?;
+        self.detect(df)
+    }
+
+    /// Reset internal state
+    fn reset(&mut self);
+}
+
+/// Builds the element-wise maximum of the given expressions.
+pub(crate) fn max_exprs(exprs: Vec<Expr>) -> Expr {
+    exprs
+        .into_iter()
+        .reduce(|max, expr| when(expr.clone().gt(max.clone())).then(expr).otherwise(max))
+        .unwrap_or(lit(f64::NAN))
+}
+
+/// Scores every row with the largest of its per-column scores.
+pub(crate) fn max_column_score(
+    df: &DataFrame,
+    column_scores: Vec<Expr>,
+) -> Result<Series, OutlierError> {
+    let score_df = df
+        .clone()
+        .lazy()
+        .select([max_exprs(column_scores)
+            .cast(DataType::Float64)
+            .alias(SCORE_COLUMN)])
+        .collect()?;
+
+    Ok(score_df
+        .column(SCORE_COLUMN)?
+        .as_materialized_series()
+        .clone())
+}
+
+/// Reads the value of `column` in a single-row statistic DataFrame.
+pub(crate) fn statistic(stats: &DataFrame, column: &str) -> Result<f64, OutlierError> {
+    let values = stats.column(column)?.cast(&DataType::Float64)?;
+
+    Ok(values.f64()?.get(0).unwrap_or(f64::NAN))
+}
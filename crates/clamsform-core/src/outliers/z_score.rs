@@ -0,0 +1,249 @@
+use polars::prelude::*;
+
+use super::error::*;
+use super::traits::*;
+use crate::scaling::standardization::error::validate_non_zero_denom;
+use crate::scaling::standardization::z_score::ZScoreScaler;
+use crate::validation::error::*;
+
+/// Scores every row with the largest absolute z-score of its values.
+pub struct ZScoreDetector {
+    threshold: f64,
+    mean: Option<DataFrame>,
+    std: Option<DataFrame>,
+}
+
+impl Default for ZScoreDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZScoreDetector {
+    pub fn new() -> Self {
+        ZScoreDetector {
+            threshold: 3.0,
+            mean: None,
+            std: None,
+        }
+    }
+
+    /// Reuses the statistics of a fitted scaler instead of fitting the detector.
+    ///
+    /// The scaler keeps unrounded statistics even when fitted with `standardize`, so the
+    /// scores match those of a detector fitted on the same data.
+    pub fn from_scaler(scaler: &ZScoreScaler) -> Result<Self, OutlierError> {
+        let (mean, std) = scaler
+            .mean()
+            .zip(scaler.std())
+            .ok_or(OutlierError::NotFittedError)?;
+        validate_non_zero_denom(std, "standard deviation")?;
+
+        Ok(ZScoreDetector {
+            threshold: 3.0,
+            mean: Some(mean.clone()),
+            std: Some(std.clone()),
+        })
+    }
+
+    /// Sets the absolute z-score above which a row is an outlier. Defaults to 3.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn mean(&self) -> Option<&DataFrame> {
+        self.mean.as_ref()
+    }
+
+    pub fn std(&self) -> Option<&DataFrame> {
+        self.std.as_ref()
+    }
+}
+
+impl OutlierDetector for ZScoreDetector {
+    fn name(&self) -> &str {
+        "z-score"
+    }
+
+    fn fit(&mut self, df: &DataFrame) -> Result<(), OutlierError> {
+        validate_dataframe(df)?;
+
+        let mean = df.clone().lazy().select([all().mean()]).collect()?;
+        let std = df.clone().lazy().select([all().std(1)]).collect()?;
+        validate_non_zero_denom(&std, "standard deviation")?;
+
+        self.mean = Some(mean);
+        self.std = Some(std);
+
+        Ok(())
+    }
+
+    fn score(&self, df: &DataFrame) -> Result<Series, OutlierError> {
+        let (mean, std) = self
+            .mean
+            .as_ref()
+            .zip(self.std.as_ref())
+            .ok_or(OutlierError::NotFittedError)?;
+
+        let fitted: Vec<&str> = mean
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.as_str())
+            .collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        let column_scores = fitted
+            .iter()
+            .map(|name| {
+                let mean = statistic(mean, name)?;
+                let std = statistic(std, name)?;
+                Ok(((col(*name) - lit(mean)) / lit(std)).abs())
+            })
+            .collect::<Result<Vec<Expr>, OutlierError>>()?;
+
+        max_column_score(df, column_scores)
+    }
+
+    fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn reset(&mut self) {
+        self.mean = None;
+        self.std = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::scaling::standardization::error::{
+        NearZeroThresholds,
+        ScalingError,
+    };
+    use crate::scaling::traits::FeatureScaler;
+
+    fn create_df() -> DataFrame {
+        df![
+            "feature1" => [1.0f64, 2.0, 3.0, 4.0, 5.0],
+            "feature2" => [10.0f64, 10.0, 10.0, 10.0, 20.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let detector = ZScoreDetector::new();
+        assert_eq!(detector.threshold(), 3.0);
+        assert!(detector.mean().is_none());
+        assert!(detector.std().is_none());
+    }
+
+    #[test]
+    fn test_from_scaler() {
+        assert!(matches!(
+            ZScoreDetector::from_scaler(&ZScoreScaler::new()),
+            Err(OutlierError::NotFittedError)
+        ));
+
+        let mut scaler = ZScoreScaler::new();
+        scaler.standardize(&create_df(), 6).unwrap();
+        let detector = ZScoreDetector::from_scaler(&scaler).unwrap();
+        assert_eq!(detector.mean(), scaler.mean());
+        assert_eq!(detector.std(), scaler.std());
+    }
+
+    #[test]
+    fn test_from_scaler_standardized() {
+        let df = df!["tiny" => [1e-3f64, 2e-3, 3e-3]].unwrap();
+        let mut scaler = ZScoreScaler::new().with_near_zero_thresholds(NearZeroThresholds {
+            absolute: 0.0,
+            relative: None,
+        });
+        scaler.standardize(&df, 2).unwrap();
+        let detector = ZScoreDetector::from_scaler(&scaler).unwrap();
+        let std = statistic(detector.std().unwrap(), "tiny").unwrap();
+        assert_abs_diff_eq!(std, 1e-3);
+    }
+
+    // Test score method
+    #[test]
+    fn test_score() {
+        let mut detector = ZScoreDetector::new();
+        detector.fit(&create_df()).unwrap();
+        let score = detector.score(&create_df()).unwrap();
+
+        assert_eq!(score.name().as_str(), SCORE_COLUMN);
+        let score: Vec<f64> = score.f64().unwrap().into_no_null_iter().collect();
+        // feature2 has a mean of 12 and a standard deviation of sqrt(20).
+        let std2 = 20.0f64.sqrt();
+        assert_abs_diff_eq!(score[0], (2.0 / 2.5f64.sqrt()).max(2.0 / std2));
+        assert_abs_diff_eq!(score[4], 8.0 / std2);
+    }
+
+    // Test detect method
+    #[test]
+    fn test_detect() {
+        let mut detector = ZScoreDetector::new().with_threshold(1.5);
+        let detected = detector.fit_detect(&create_df()).unwrap();
+
+        assert_eq!(detected.get_column_names(), [MASK_COLUMN, SCORE_COLUMN]);
+        let mask: Vec<bool> = detected
+            .column(MASK_COLUMN)
+            .unwrap()
+            .bool()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(mask, [false, false, false, false, true]);
+    }
+
+    #[test]
+    fn test_fit_zero_spread() {
+        let df = df!["constant" => [1.0f64, 1.0]].unwrap();
+        assert!(matches!(
+            ZScoreDetector::new().fit(&df),
+            Err(OutlierError::ScalingError(ScalingError::ZeroDenominatorError(_, ref cols))) if cols == "constant"
+        ));
+    }
+
+    #[test]
+    fn test_fit_float32() {
+        let df = df!["feature1" => [1.0f32, 2.0, 3.0, 4.0, 50.0]].unwrap();
+        let mut detector = ZScoreDetector::new().with_threshold(1.5);
+        let detected = detector.fit_detect(&df).unwrap();
+
+        let mask: Vec<bool> = detected
+            .column(MASK_COLUMN)
+            .unwrap()
+            .bool()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(mask, [false, false, false, false, true]);
+    }
+
+    #[test]
+    fn test_score_not_fitted() {
+        let detector = ZScoreDetector::new();
+        assert!(matches!(
+            detector.score(&create_df()),
+            Err(OutlierError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut detector = ZScoreDetector::new();
+        detector.fit(&create_df()).unwrap();
+
+        detector.reset();
+        assert!(detector.mean().is_none());
+        assert!(detector.std().is_none());
+    }
+}
@@ -0,0 +1,16 @@
+use polars::prelude::*;
+use thiserror::Error;
+
+use crate::validation::error::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum ProfilingError {
+    #[error("The profile could not be serialized: {0}")]
+    SerializationError(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
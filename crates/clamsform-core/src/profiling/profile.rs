@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+
+use polars::prelude::*;
+use serde::Serialize;
+
+use super::error::*;
+use crate::validation::error::{
+    count_invalid_values,
+    validate_not_empty_df,
+};
+
+/// The quantiles reported for every numeric column.
+pub const PROFILE_QUANTILES: [f64; 5] = [0.05, 0.25, 0.5, 0.75, 0.95];
+
+/// Summary statistics for a single column.
+///
+/// Counts cover every column. The remaining statistics are only computed for numeric
+/// columns and are taken over the finite values, ignoring nulls, NaN and infinite values.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColumnProfile {
+    pub name: String,
+    pub dtype: String,
+    pub count: usize,
+    pub null_count: usize,
+    pub nan_count: usize,
+    pub inf_count: usize,
+    pub mean: Option<f64>,
+    pub std: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub quantiles: BTreeMap<String, Option<f64>>,
+    pub skewness: Option<f64>,
+    pub kurtosis: Option<f64>,
+    pub distinct_count: usize,
+    pub zero_fraction: Option<f64>,
+}
+
+/// A per-column profile of a DataFrame.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Profile {
+    pub columns: Vec<ColumnProfile>,
+}
+
+impl Profile {
+    /// Returns the profile of the named column, if present.
+    pub fn column(&self, name: &str) -> Option<&ColumnProfile> {
+        self.columns.iter().find(|profile| profile.name == name)
+    }
+
+    /// Returns the profile as a DataFrame with one row per profiled column.
+    pub fn to_dataframe(&self) -> Result<DataFrame, ProfilingError> {
+        let float_column = |name: &str, getter: fn(&ColumnProfile) -> Option<f64>| {
+            Column::new(
+                name.into(),
+                self.columns.iter().map(getter).collect::<Vec<_>>(),
+            )
+        };
+        let count_column = |name: &str, getter: fn(&ColumnProfile) -> usize| {
+            Column::new(
+                name.into(),
+                self.columns
+                    .iter()
+                    .map(|profile| getter(profile) as u64)
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let mut columns = vec![
+            Column::new(
+                "column".into(),
+                self.columns
+                    .iter()
+                    .map(|profile| profile.name.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "dtype".into(),
+                self.columns
+                    .iter()
+                    .map(|profile| profile.dtype.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            count_column("count", |profile| profile.count),
+            count_column("null_count", |profile| profile.null_count),
+            count_column("nan_count", |profile| profile.nan_count),
+            count_column("inf_count", |profile| profile.inf_count),
+            float_column("mean", |profile| profile.mean),
+            float_column("std", |profile| profile.std),
+            float_column("min", |profile| profile.min),
+            float_column("max", |profile| profile.max),
+        ];
+
+        for quantile in PROFILE_QUANTILES {
+            let label = quantile_label(quantile);
+            columns.push(Column::new(
+                label.as_str().into(),
+                self.columns
+                    .iter()
+                    .map(|profile| profile.quantiles.get(&label).copied().flatten())
+                    .collect::<Vec<_>>(),
+            ));
+        }
+
+        columns.extend([
+            float_column("skewness", |profile| profile.skewness),
+            float_column("kurtosis", |profile| profile.kurtosis),
+            count_column("distinct_count", |profile| profile.distinct_count),
+            float_column("zero_fraction", |profile| profile.zero_fraction),
+        ]);
+
+        Ok(DataFrame::new(columns)?)
+    }
+
+    /// Serializes the profile to a JSON string.
+    pub fn to_json(&self) -> Result<String, ProfilingError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| ProfilingError::SerializationError(e.to_string()))
+    }
+}
+
+/// Formats a quantile as a column label, e.g. `0.05` becomes `q05`.
+fn quantile_label(quantile: f64) -> String {
+    format!("q{:02}", (quantile * 100.0).round() as u32)
+}
+
+/// Extracts the first value of a statistics column as an `f64`.
+fn stat_value(stats: &DataFrame, name: &str) -> Result<Option<f64>, ProfilingError> {
+    Ok(stats.column(name)?.get(0)?.extract::<f64>())
+}
+
+/// Profiles every column of the DataFrame.
+///
+/// # Arguments
+/// * `df` - The DataFrame to profile.
+///
+/// # Returns
+/// * `Ok(Profile)` with one `ColumnProfile` per column, in column order.
+/// * `Err(ProfilingError::ValidationError)` if the DataFrame is empty.
+pub fn profile(df: &DataFrame) -> Result<Profile, ProfilingError> {
+    validate_not_empty_df(df)?;
+
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(profile_column)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Profile { columns })
+}
+
+fn profile_column(column: &Column) -> Result<ColumnProfile, ProfilingError> {
+    let series = column.as_materialized_series();
+    let invalid_counts = count_invalid_values(column)?;
+    let null_count = invalid_counts.null;
+
+    let mut column_profile = ColumnProfile {
+        name: series.name().to_string(),
+        dtype: series.dtype().to_string(),
+        count: series.len() - null_count,
+        null_count,
+        nan_count: invalid_counts.nan,
+        inf_count: invalid_counts.infinite,
+        mean: None,
+        std: None,
+        min: None,
+        max: None,
+        quantiles: BTreeMap::new(),
+        skewness: None,
+        kurtosis: None,
+        distinct_count: series.drop_nulls().n_unique()?,
+        zero_fraction: None,
+    };
+
+    if !series.dtype().is_primitive_numeric() {
+        return Ok(column_profile);
+    }
+
+    let values = series.cast(&DataType::Float64)?;
+    let values = values.f64()?;
+    let finite: Vec<f64> = values
+        .into_iter()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .collect();
+    if !finite.is_empty() {
+        let zeros = finite.iter().filter(|v| **v == 0.0).count();
+        column_profile.zero_fraction = Some(zeros as f64 / finite.len() as f64);
+    }
+
+    let mut exprs = vec![
+        col("value").mean().alias("mean"),
+        col("value").std(1).alias("std"),
+        col("value").min().alias("min"),
+        col("value").max().alias("max"),
+        col("value").skew(true).alias("skewness"),
+        col("value").kurtosis(true, true).alias("kurtosis"),
+    ];
+    for quantile in PROFILE_QUANTILES {
+        exprs.push(
+            col("value")
+                .quantile(lit(quantile), QuantileMethod::Linear)
+                .alias(quantile_label(quantile)),
+        );
+    }
+
+    let stats = df!["value" => finite]?.lazy().select(exprs).collect()?;
+
+    column_profile.mean = stat_value(&stats, "mean")?;
+    column_profile.std = stat_value(&stats, "std")?;
+    column_profile.min = stat_value(&stats, "min")?;
+    column_profile.max = stat_value(&stats, "max")?;
+    column_profile.skewness = stat_value(&stats, "skewness")?;
+    column_profile.kurtosis = stat_value(&stats, "kurtosis")?;
+    for quantile in PROFILE_QUANTILES {
+        let label = quantile_label(quantile);
+        let value = stat_value(&stats, &label)?;
+        column_profile.quantiles.insert(label, value);
+    }
+
+    Ok(column_profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "feature" => [Some(0.0f64), Some(1.0), Some(2.0), Some(3.0), Some(4.0), None],
+            "dirty" => [Some(1.0f64), Some(f64::NAN), Some(f64::INFINITY), Some(0.0), Some(3.0), Some(0.0)],
+            "label" => ["a", "b", "a", "c", "a", "b"]
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_profile_empty_df() {
+        let result = profile(&DataFrame::default());
+        assert!(matches!(result, Err(ProfilingError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_profile_numeric_column() {
+        let profile = profile(&create_df()).unwrap();
+        let feature = profile.column("feature").unwrap();
+
+        assert_eq!(feature.dtype, "f64");
+        assert_eq!(feature.count, 5);
+        assert_eq!(feature.null_count, 1);
+        assert_eq!(feature.mean, Some(2.0));
+        assert_abs_diff_eq!(feature.std.unwrap(), 1.5811, epsilon = 1e-4);
+        assert_eq!(feature.min, Some(0.0));
+        assert_eq!(feature.max, Some(4.0));
+        assert_eq!(feature.quantiles["q50"], Some(2.0));
+        assert_eq!(feature.quantiles["q25"], Some(1.0));
+        assert_abs_diff_eq!(feature.skewness.unwrap(), 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(feature.kurtosis.unwrap(), -1.3, epsilon = 1e-12);
+        assert_eq!(feature.distinct_count, 5);
+        assert_eq!(feature.zero_fraction, Some(0.2));
+    }
+
+    #[test]
+    fn test_profile_dirty_column() {
+        let profile = profile(&create_df()).unwrap();
+        let dirty = profile.column("dirty").unwrap();
+
+        assert_eq!(dirty.count, 6);
+        assert_eq!(dirty.nan_count, 1);
+        assert_eq!(dirty.inf_count, 1);
+        assert_eq!(dirty.mean, Some(1.0));
+        assert_eq!(dirty.max, Some(3.0));
+        assert_abs_diff_eq!(dirty.zero_fraction.unwrap(), 2.0 / 4.0);
+    }
+
+    #[test]
+    fn test_profile_non_numeric_column() {
+        let profile = profile(&create_df()).unwrap();
+        let label = profile.column("label").unwrap();
+
+        assert_eq!(label.dtype, "str");
+        assert_eq!(label.count, 6);
+        assert_eq!(label.distinct_count, 3);
+        assert!(label.mean.is_none());
+        assert!(label.quantiles.is_empty());
+        assert!(label.zero_fraction.is_none());
+    }
+
+    #[test]
+    fn test_profile_to_dataframe() {
+        let profile_df = profile(&create_df()).unwrap().to_dataframe().unwrap();
+
+        assert_eq!(profile_df.height(), 3);
+        assert_eq!(
+            profile_df.column("column").unwrap().get(1).unwrap(),
+            AnyValue::String("dirty")
+        );
+        assert_eq!(
+            profile_df.column("q50").unwrap().get(0).unwrap(),
+            AnyValue::Float64(2.0)
+        );
+        assert!(profile_df.column("mean").unwrap().get(2).unwrap().is_null());
+    }
+
+    #[test]
+    fn test_profile_to_json() {
+        let json = profile(&create_df()).unwrap().to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["columns"][0]["name"], "feature");
+        assert_eq!(value["columns"][0]["quantiles"]["q50"], 2.0);
+        assert!(value["columns"][2]["mean"].is_null());
+    }
+}
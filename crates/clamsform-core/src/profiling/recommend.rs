@@ -0,0 +1,364 @@
+use polars::prelude::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::error::*;
+use super::profile::*;
+use crate::scaling::standardization::error::{
+    validate_denoms,
+    NearZeroThresholds,
+};
+use crate::scaling::standardization::max_abs::MaxAbsScaler;
+use crate::scaling::standardization::min_max::MinMaxScaler;
+use crate::scaling::standardization::power::PowerTransformer;
+use crate::scaling::standardization::robust::RobustScaler;
+use crate::scaling::standardization::z_score::ZScoreScaler;
+use crate::scaling::traits::FeatureScaler;
+use crate::validation::error::validate_constant_columns;
+
+/// The scaling strategies a column can be assigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalingStrategy {
+    ZScore,
+    Robust,
+    MinMax,
+    MaxAbs,
+    PowerTransform,
+    Drop,
+}
+
+impl ScalingStrategy {
+    /// Instantiates the scaler implementing this strategy.
+    ///
+    /// Returns `None` for `Drop`, as dropped columns are not scaled.
+    pub fn scaler(&self) -> Option<Box<dyn FeatureScaler>> {
+        match self {
+            ScalingStrategy::ZScore => Some(Box::new(ZScoreScaler::new())),
+            ScalingStrategy::Robust => Some(Box::new(RobustScaler::new())),
+            ScalingStrategy::MinMax => Some(Box::new(MinMaxScaler::new())),
+            ScalingStrategy::MaxAbs => Some(Box::new(MaxAbsScaler::new())),
+            ScalingStrategy::PowerTransform => Some(Box::new(PowerTransformer::new())),
+            ScalingStrategy::Drop => None,
+        }
+    }
+}
+
+/// Thresholds used to map column characteristics to a scaling strategy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecommendationThresholds {
+    /// Columns with an absolute skewness at or above this value get a power transform.
+    pub skewness: f64,
+    /// Columns with values further than this many IQRs beyond the quartiles get a robust scaler.
+    pub outlier_iqr_multiplier: f64,
+    /// Columns with a zero fraction at or above this value get a max-abs scaler.
+    pub sparsity: f64,
+    /// Columns with an excess kurtosis at or below this value get a min-max scaler.
+    pub platykurtosis: f64,
+    /// Thresholds for the standard deviation denominator check.
+    pub near_zero: NearZeroThresholds,
+}
+
+impl Default for RecommendationThresholds {
+    fn default() -> Self {
+        RecommendationThresholds {
+            skewness: 1.0,
+            outlier_iqr_multiplier: 3.0,
+            sparsity: 0.5,
+            platykurtosis: -1.0,
+            near_zero: NearZeroThresholds::default(),
+        }
+    }
+}
+
+/// The strategy recommended for a single column, with a short reason.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnRecommendation {
+    pub column: String,
+    pub strategy: ScalingStrategy,
+    pub reason: String,
+}
+
+/// A reviewable plan assigning a scaling strategy to every column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScalingPlan {
+    pub columns: Vec<ColumnRecommendation>,
+}
+
+impl ScalingPlan {
+    /// Returns the names of the columns assigned to `strategy`.
+    pub fn columns_for(&self, strategy: ScalingStrategy) -> Vec<&str> {
+        self.columns
+            .iter()
+            .filter(|recommendation| recommendation.strategy == strategy)
+            .map(|recommendation| recommendation.column.as_str())
+            .collect()
+    }
+
+    /// Returns the plan as a DataFrame with `column`, `strategy` and `reason` columns.
+    pub fn to_dataframe(&self) -> Result<DataFrame, ProfilingError> {
+        let strategies = self
+            .columns
+            .iter()
+            .map(|recommendation| {
+                serde_json::to_value(recommendation.strategy)
+                    .map(|value| value.as_str().unwrap_or_default().to_string())
+                    .map_err(|e| ProfilingError::SerializationError(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let plan_df = df![
+            "column" => self.columns.iter().map(|r| r.column.as_str()).collect::<Vec<_>>(),
+            "strategy" => strategies,
+            "reason" => self.columns.iter().map(|r| r.reason.as_str()).collect::<Vec<_>>()
+        ]?;
+
+        Ok(plan_df)
+    }
+
+    /// Serializes the plan to a JSON string.
+    pub fn to_json(&self) -> Result<String, ProfilingError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| ProfilingError::SerializationError(e.to_string()))
+    }
+
+    /// Parses a plan from a JSON string, e.g. one that was reviewed and edited.
+    pub fn from_json(json: &str) -> Result<Self, ProfilingError> {
+        serde_json::from_str(json).map_err(|e| ProfilingError::SerializationError(e.to_string()))
+    }
+}
+
+/// Recommends a scaling strategy for every column of the DataFrame.
+///
+/// Columns are assigned the first matching strategy, in the following order:
+/// - `Drop` if the column is non-numeric, constant, or has a near-zero standard deviation.
+/// - `MaxAbs` if the column is sparse, to preserve its zeros.
+/// - `Robust` if the column has values far beyond its interquartile range.
+/// - `PowerTransform` if the column is strongly skewed.
+/// - `MinMax` if the column is flat and bounded, such as uniformly distributed data.
+/// - `ZScore` otherwise.
+///
+/// # Arguments
+/// * `df` - The DataFrame to analyze.
+/// * `thresholds` - The thresholds used to classify each column.
+pub fn recommend(
+    df: &DataFrame,
+    thresholds: &RecommendationThresholds,
+) -> Result<ScalingPlan, ProfilingError> {
+    let profile = profile(df)?;
+
+    let columns = profile
+        .columns
+        .iter()
+        .map(|column_profile| recommend_column(df, column_profile, thresholds))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ScalingPlan { columns })
+}
+
+fn recommend_column(
+    df: &DataFrame,
+    column_profile: &ColumnProfile,
+    thresholds: &RecommendationThresholds,
+) -> Result<ColumnRecommendation, ProfilingError> {
+    let name = column_profile.name.as_str();
+    let column_df = df.select([name])?;
+
+    let recommendation = |strategy: ScalingStrategy, reason: String| {
+        Ok(ColumnRecommendation {
+            column: name.to_string(),
+            strategy,
+            reason,
+        })
+    };
+
+    if !column_df.get_columns()[0].dtype().is_primitive_numeric() {
+        return recommendation(
+            ScalingStrategy::Drop,
+            format!("non-numeric data type {}", column_profile.dtype),
+        );
+    }
+
+    let (Some(mean), Some(std)) = (column_profile.mean, column_profile.std) else {
+        return recommendation(
+            ScalingStrategy::Drop,
+            "fewer than two finite values".to_string(),
+        );
+    };
+
+    if validate_constant_columns(&column_df).is_err() {
+        return recommendation(ScalingStrategy::Drop, "constant column".to_string());
+    }
+
+    let std_df = df![name => [std]]?;
+    let mean_df = df![name => [mean]]?;
+    if validate_denoms(
+        &std_df,
+        Some(&mean_df),
+        "standard deviation",
+        &thresholds.near_zero,
+    )
+    .is_err()
+    {
+        return recommendation(
+            ScalingStrategy::Drop,
+            format!("near-zero standard deviation ({std:.3e})"),
+        );
+    }
+
+    if let Some(zero_fraction) = column_profile.zero_fraction {
+        if zero_fraction >= thresholds.sparsity {
+            return recommendation(
+                ScalingStrategy::MaxAbs,
+                format!("sparse column ({:.0}% zeros)", zero_fraction * 100.0),
+            );
+        }
+    }
+
+    let quantile = |label: &str| column_profile.quantiles.get(label).copied().flatten();
+    if let (Some(q25), Some(q75), Some(min), Some(max)) = (
+        quantile("q25"),
+        quantile("q75"),
+        column_profile.min,
+        column_profile.max,
+    ) {
+        let iqr = q75 - q25;
+        let fence = thresholds.outlier_iqr_multiplier * iqr;
+        if iqr > 0.0 && (min < q25 - fence || max > q75 + fence) {
+            return recommendation(
+                ScalingStrategy::Robust,
+                format!(
+                    "outliers beyond {} IQRs from the quartiles",
+                    thresholds.outlier_iqr_multiplier
+                ),
+            );
+        }
+    }
+
+    if let Some(skewness) = column_profile.skewness {
+        if skewness.abs() >= thresholds.skewness {
+            return recommendation(
+                ScalingStrategy::PowerTransform,
+                format!("skewed distribution (skewness {skewness:.2})"),
+            );
+        }
+    }
+
+    if let Some(kurtosis) = column_profile.kurtosis {
+        if kurtosis <= thresholds.platykurtosis {
+            return recommendation(
+                ScalingStrategy::MinMax,
+                format!("flat, bounded distribution (excess kurtosis {kurtosis:.2})"),
+            );
+        }
+    }
+
+    recommendation(
+        ScalingStrategy::ZScore,
+        "approximately symmetric distribution without outliers".to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "normal" => [-1.2f64, -0.5, -0.1, 0.0, 0.1, 0.4, 0.9, 1.3, -0.8, 0.2],
+            "uniform" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
+            "sparse" => [0.0f64, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 5.0, 1.0],
+            "outliers" => [1.0f64, 2.0, 3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0, 100.0],
+            "skewed" => [1.0f64, 1.0, 2.0, 2.0, 3.0, 4.0, 6.0, 9.0, 14.0, 22.0],
+            "constant" => [5.0f64; 10],
+            "label" => ["a", "b", "a", "b", "a", "b", "a", "b", "a", "b"]
+        ]
+        .unwrap()
+    }
+
+    fn strategy_of(plan: &ScalingPlan, column: &str) -> ScalingStrategy {
+        plan.columns
+            .iter()
+            .find(|recommendation| recommendation.column == column)
+            .unwrap()
+            .strategy
+    }
+
+    #[test]
+    fn test_recommend() {
+        let plan = recommend(&create_df(), &RecommendationThresholds::default()).unwrap();
+
+        assert_eq!(strategy_of(&plan, "normal"), ScalingStrategy::ZScore);
+        assert_eq!(strategy_of(&plan, "uniform"), ScalingStrategy::MinMax);
+        assert_eq!(strategy_of(&plan, "sparse"), ScalingStrategy::MaxAbs);
+        assert_eq!(strategy_of(&plan, "outliers"), ScalingStrategy::Robust);
+        assert_eq!(
+            strategy_of(&plan, "skewed"),
+            ScalingStrategy::PowerTransform
+        );
+        assert_eq!(strategy_of(&plan, "constant"), ScalingStrategy::Drop);
+        assert_eq!(strategy_of(&plan, "label"), ScalingStrategy::Drop);
+    }
+
+    #[test]
+    fn test_recommend_near_zero_std() {
+        let df = df!["tiny" => [1e9f64, 1e9 + 1.0, 1e9 + 2.0]].unwrap();
+        let thresholds = RecommendationThresholds {
+            near_zero: NearZeroThresholds {
+                absolute: 1e-8,
+                relative: Some(1e-6),
+            },
+            ..Default::default()
+        };
+
+        let plan = recommend(&df, &thresholds).unwrap();
+        assert_eq!(strategy_of(&plan, "tiny"), ScalingStrategy::Drop);
+    }
+
+    #[test]
+    fn test_columns_for() {
+        let plan = recommend(&create_df(), &RecommendationThresholds::default()).unwrap();
+        assert_eq!(
+            plan.columns_for(ScalingStrategy::Drop),
+            vec!["constant", "label"]
+        );
+    }
+
+    #[test]
+    fn test_scaler() {
+        assert!(ScalingStrategy::Drop.scaler().is_none());
+
+        let df = create_df();
+        let plan = recommend(&df, &RecommendationThresholds::default()).unwrap();
+        for strategy in [
+            ScalingStrategy::ZScore,
+            ScalingStrategy::Robust,
+            ScalingStrategy::MinMax,
+            ScalingStrategy::MaxAbs,
+            ScalingStrategy::PowerTransform,
+        ] {
+            let columns = plan.columns_for(strategy);
+            let mut scaler = strategy.scaler().unwrap();
+            let scaled_df = scaler.standardize(&df.select(columns).unwrap(), 4).unwrap();
+            assert_eq!(scaled_df.width(), 1);
+        }
+    }
+
+    #[test]
+    fn test_plan_serialization() {
+        let plan = recommend(&create_df(), &RecommendationThresholds::default()).unwrap();
+
+        let json = plan.to_json().unwrap();
+        assert!(json.contains("\"strategy\": \"power_transform\""));
+        assert_eq!(ScalingPlan::from_json(&json).unwrap(), plan);
+
+        let plan_df = plan.to_dataframe().unwrap();
+        assert_eq!(plan_df.shape(), (7, 3));
+        assert_eq!(
+            plan_df.column("strategy").unwrap().get(0).unwrap(),
+            AnyValue::String("z_score")
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+
+use polars::prelude::*;
+
+use super::error::*;
+use crate::validation::error::*;
+
+/// How the threshold of a column is chosen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThresholdRule {
+    /// A fixed threshold.
+    Value(f64),
+    /// The mean of the column at fit time.
+    Mean,
+    /// The median of the column at fit time.
+    Median,
+    /// The given quantile, between 0 and 1, of the column at fit time.
+    Quantile(f64),
+}
+
+/// Thresholds numeric columns into UInt8 columns holding 1 above the threshold and 0 otherwise.
+pub struct Binarizer {
+    default_rule: ThresholdRule,
+    column_rules: HashMap<String, ThresholdRule>,
+    inclusive: bool,
+    thresholds: Option<Vec<(String, f64)>>,
+}
+
+impl Default for Binarizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Binarizer {
+    pub fn new() -> Self {
+        Binarizer {
+            default_rule: ThresholdRule::Value(0.0),
+            column_rules: HashMap::new(),
+            inclusive: false,
+            thresholds: None,
+        }
+    }
+
+    /// Sets the rule of the columns without a rule of their own. Defaults to a threshold of 0.
+    pub fn with_default_rule(mut self, rule: ThresholdRule) -> Self {
+        self.default_rule = rule;
+        self
+    }
+
+    /// Sets the rule of `column`.
+    pub fn with_rule(mut self, column: &str, rule: ThresholdRule) -> Self {
+        self.column_rules.insert(column.to_string(), rule);
+        self
+    }
+
+    /// Maps values equal to the threshold to 1 instead of 0.
+    pub fn with_inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = inclusive;
+        self
+    }
+
+    /// The threshold of every fitted column.
+    pub fn thresholds(&self) -> Option<&[(String, f64)]> {
+        self.thresholds.as_deref()
+    }
+
+    fn threshold_expr(name: &str, rule: ThresholdRule) -> Expr {
+        match rule {
+            ThresholdRule::Value(value) => lit(value),
+            ThresholdRule::Mean => col(name).mean(),
+            ThresholdRule::Median => col(name).median(),
+            ThresholdRule::Quantile(q) => col(name).quantile(lit(q), QuantileMethod::Linear),
+        }
+        .cast(DataType::Float64)
+        .alias(name)
+    }
+
+    /// Learns the threshold of every column.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), DiscretizationError> {
+        let invalid_quantiles: Vec<String> = self
+            .column_rules
+            .values()
+            .chain(std::iter::once(&self.default_rule))
+            .filter_map(|rule| match rule {
+                ThresholdRule::Quantile(q) if !(0.0..=1.0).contains(q) => Some(q.to_string()),
+                _ => None,
+            })
+            .collect();
+        if !invalid_quantiles.is_empty() {
+            return Err(DiscretizationError::InvalidParameterError(format!(
+                "quantiles must be between 0 and 1, got {}",
+                invalid_quantiles.join(", ")
+            )));
+        }
+
+        let mut rule_cols: Vec<&str> = self.column_rules.keys().map(|name| name.as_str()).collect();
+        rule_cols.sort();
+        validate_columns_present(df, &rule_cols)?;
+
+        validate_dataframe(df)?;
+
+        let exprs: Vec<Expr> = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| {
+                let rule = self
+                    .column_rules
+                    .get(name.as_str())
+                    .copied()
+                    .unwrap_or(self.default_rule);
+                Self::threshold_expr(name, rule)
+            })
+            .collect();
+
+        let threshold_df = df.clone().lazy().select(exprs).collect()?;
+
+        let thresholds = threshold_df
+            .get_columns()
+            .iter()
+            .map(|threshold| {
+                let value = threshold.f64()?.get(0).unwrap_or(f64::NAN);
+                Ok((threshold.name().to_string(), value))
+            })
+            .collect::<Result<Vec<_>, DiscretizationError>>()?;
+
+        self.thresholds = Some(thresholds);
+
+        Ok(())
+    }
+
+    /// Replaces the fitted columns with their binarized values. Other columns are passed through.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, DiscretizationError> {
+        let thresholds = self
+            .thresholds
+            .as_ref()
+            .ok_or(DiscretizationError::NotFittedError)?;
+
+        let fitted: Vec<&str> = thresholds.iter().map(|(name, _)| name.as_str()).collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        let exprs: Vec<Expr> = thresholds
+            .iter()
+            .map(|(name, threshold)| {
+                let above = if self.inclusive {
+                    col(name.as_str()).gt_eq(lit(*threshold))
+                } else {
+                    col(name.as_str()).gt(lit(*threshold))
+                };
+                above.cast(DataType::UInt8).alias(name.as_str())
+            })
+            .collect();
+
+        let binarized_df = df.clone().lazy().with_columns(exprs).collect()?;
+
+        Ok(binarized_df)
+    }
+
+    /// Learns the thresholds and returns the binarized DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, DiscretizationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.thresholds = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "feature1" => [-1.0f64, 0.0, 2.0, 5.0],
+            "feature2" => [1.0f64, 2.0, 3.0, 10.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let binarizer = Binarizer::new();
+        assert_eq!(binarizer.default_rule, ThresholdRule::Value(0.0));
+        assert!(binarizer.column_rules.is_empty());
+        assert!(!binarizer.inclusive);
+        assert!(binarizer.thresholds().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_rules() {
+        let mut binarizer = Binarizer::new()
+            .with_default_rule(ThresholdRule::Median)
+            .with_rule("feature2", ThresholdRule::Mean);
+        binarizer.fit(&create_df()).unwrap();
+
+        assert_eq!(
+            binarizer.thresholds().unwrap(),
+            [("feature1".to_string(), 1.0), ("feature2".to_string(), 4.0)]
+        );
+    }
+
+    #[test]
+    fn test_fit_quantile() {
+        let mut binarizer = Binarizer::new().with_default_rule(ThresholdRule::Quantile(0.25));
+        binarizer.fit(&create_df()).unwrap();
+
+        assert_eq!(binarizer.thresholds().unwrap()[1].1, 1.75);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut binarizer = Binarizer::new().with_rule("feature1", ThresholdRule::Quantile(1.5));
+        assert!(matches!(
+            binarizer.fit(&create_df()),
+            Err(DiscretizationError::InvalidParameterError(_))
+        ));
+
+        let mut binarizer = Binarizer::new().with_rule("absent", ThresholdRule::Mean);
+        assert!(matches!(
+            binarizer.fit(&create_df()),
+            Err(DiscretizationError::ValidationError(
+                ValidationError::MissingColumnsError(ref cols)
+            )) if cols == "absent"
+        ));
+
+        let df = df!["label" => ["a", "b"]].unwrap();
+        assert!(matches!(
+            Binarizer::new().fit(&df),
+            Err(DiscretizationError::ValidationError(
+                ValidationError::NonNumericError(_)
+            ))
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_exclusive() {
+        let mut binarizer = Binarizer::new().with_rule("feature2", ThresholdRule::Value(3.0));
+        let binarized_df = binarizer.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "feature1" => [0u8, 0, 1, 1],
+            "feature2" => [0u8, 0, 0, 1]
+        ]
+        .unwrap();
+        assert_eq!(binarized_df, expected);
+    }
+
+    #[test]
+    fn test_transform_inclusive() {
+        let mut binarizer = Binarizer::new()
+            .with_rule("feature2", ThresholdRule::Value(3.0))
+            .with_inclusive(true);
+        let binarized_df = binarizer.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "feature1" => [0u8, 1, 1, 1],
+            "feature2" => [0u8, 0, 1, 1]
+        ]
+        .unwrap();
+        assert_eq!(binarized_df, expected);
+    }
+
+    #[test]
+    fn test_transform_passes_through_other_columns() {
+        let mut binarizer = Binarizer::new();
+        binarizer
+            .fit(&create_df().select(["feature1"]).unwrap())
+            .unwrap();
+
+        let df = df![
+            "feature1" => [1.0f64, -1.0],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        let expected = df![
+            "feature1" => [1u8, 0],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        assert_eq!(binarizer.transform(&df).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let binarizer = Binarizer::new();
+        assert!(matches!(
+            binarizer.transform(&create_df()),
+            Err(DiscretizationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut binarizer = Binarizer::new();
+        binarizer.fit(&create_df()).unwrap();
+
+        binarizer.reset();
+        assert!(binarizer.thresholds().is_none());
+    }
+}
@@ -0,0 +1,22 @@
+use polars::prelude::*;
+use thiserror::Error;
+
+use crate::validation::error::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum DiscretizationError {
+    #[error(
+        "The discretizer has not been fitted. \
+        Call `fit` before attempting to transform a DataFrame."
+    )]
+    NotFittedError,
+
+    #[error("Invalid discretizer parameter: {0}")]
+    InvalidParameterError(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
@@ -0,0 +1,473 @@
+use polars::prelude::*;
+
+use super::error::*;
+use crate::validation::error::*;
+
+/// Edges closer than this fraction of the column range are merged, so that no bin is empty
+/// by construction.
+const EDGE_TOLERANCE: f64 = 1e-8;
+const KMEANS_MAX_ITER: usize = 100;
+
+/// How the bin edges are learned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinStrategy {
+    /// Bins of equal width between the minimum and the maximum.
+    Uniform,
+    /// Bins holding the same number of values.
+    Quantile,
+    /// Bins whose values are closest to the same center of a 1-D k-means clustering.
+    KMeans,
+}
+
+/// How the bin of every value is returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinEncoding {
+    /// A single Int64 column holding the bin index.
+    Ordinal,
+    /// One UInt8 column per bin, named `<col>_<bin>`.
+    OneHot,
+}
+
+/// Discretizes numeric columns into bins.
+///
+/// Edges that coincide, as on low-cardinality columns, are merged, so that a column can
+/// end up with fewer bins than requested. A constant column gets a single bin. Values
+/// outside the fitted range fall in the first or last bin.
+pub struct KBinsDiscretizer {
+    n_bins: usize,
+    strategy: BinStrategy,
+    encoding: BinEncoding,
+    bin_edges: Option<Vec<(String, Vec<f64>)>>,
+}
+
+impl Default for KBinsDiscretizer {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+impl KBinsDiscretizer {
+    pub fn new(n_bins: usize) -> Self {
+        KBinsDiscretizer {
+            n_bins,
+            strategy: BinStrategy::Quantile,
+            encoding: BinEncoding::Ordinal,
+            bin_edges: None,
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: BinStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn with_encoding(mut self, encoding: BinEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn n_bins(&self) -> usize {
+        self.n_bins
+    }
+
+    /// The learned edges of every fitted column, from the minimum to the maximum.
+    pub fn bin_edges(&self) -> Option<&[(String, Vec<f64>)]> {
+        self.bin_edges.as_deref()
+    }
+
+    /// Returns the names of the output columns of the fitted columns.
+    pub fn feature_names_out(&self) -> Option<Vec<String>> {
+        self.bin_edges.as_ref().map(|bin_edges| {
+            bin_edges
+                .iter()
+                .flat_map(|(name, edges)| match self.encoding {
+                    BinEncoding::Ordinal => vec![name.clone()],
+                    BinEncoding::OneHot => (0..edges.len() - 1)
+                        .map(|bin| format!("{name}_{bin}"))
+                        .collect(),
+                })
+                .collect()
+        })
+    }
+
+    fn column_edges(&self, df: &DataFrame, name: &str) -> Result<Vec<f64>, DiscretizationError> {
+        let column = df.column(name)?.as_materialized_series();
+        let (Some(min), Some(max)) = (column.min::<f64>()?, column.max::<f64>()?) else {
+            return Ok(vec![f64::NEG_INFINITY, f64::INFINITY]);
+        };
+
+        let uniform: Vec<f64> = (0..=self.n_bins)
+            .map(|i| min + (max - min) * i as f64 / self.n_bins as f64)
+            .collect();
+
+        let edges = match self.strategy {
+            BinStrategy::Uniform => uniform,
+            BinStrategy::Quantile => {
+                let quantiles = df
+                    .clone()
+                    .lazy()
+                    .select(
+                        (0..=self.n_bins)
+                            .map(|i| {
+                                col(name)
+                                    .cast(DataType::Float64)
+                                    .quantile(
+                                        lit(i as f64 / self.n_bins as f64),
+                                        QuantileMethod::Linear,
+                                    )
+                                    .alias(format!("q{i}"))
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .collect()?;
+
+                quantiles
+                    .get_columns()
+                    .iter()
+                    .map(|q| Ok(q.f64()?.get(0).unwrap_or(min)))
+                    .collect::<Result<Vec<f64>, DiscretizationError>>()?
+            },
+            BinStrategy::KMeans => {
+                let values: Vec<f64> = column
+                    .cast(&DataType::Float64)?
+                    .f64()?
+                    .into_no_null_iter()
+                    .collect();
+                let initial_centers: Vec<f64> =
+                    uniform.windows(2).map(|w| (w[0] + w[1]) / 2.0).collect();
+                let centers = kmeans_1d(&values, initial_centers, EDGE_TOLERANCE * (max - min));
+
+                std::iter::once(min)
+                    .chain(centers.windows(2).map(|w| (w[0] + w[1]) / 2.0))
+                    .chain(std::iter::once(max))
+                    .collect()
+            },
+        };
+
+        let tolerance = EDGE_TOLERANCE * (max - min);
+        let mut unique_edges: Vec<f64> = Vec::with_capacity(edges.len());
+        for edge in edges {
+            if unique_edges
+                .last()
+                .is_none_or(|last| edge - last > tolerance)
+            {
+                unique_edges.push(edge);
+            }
+        }
+
+        if unique_edges.len() < 2 {
+            unique_edges = vec![f64::NEG_INFINITY, f64::INFINITY];
+        }
+
+        Ok(unique_edges)
+    }
+
+    /// Returns the name and expression of every output column of a fitted column.
+    fn binned_columns(&self, name: &str, edges: &[f64]) -> Vec<(String, Expr)> {
+        let inner_edges = &edges[1..edges.len() - 1];
+        let bin = inner_edges.iter().fold(lit(0i64), |acc, edge| {
+            acc + col(name).gt_eq(lit(*edge)).cast(DataType::Int64)
+        });
+
+        match self.encoding {
+            BinEncoding::Ordinal => vec![(name.to_string(), bin.alias(name))],
+            BinEncoding::OneHot => (0..edges.len() - 1)
+                .map(|i| {
+                    let column = format!("{name}_{i}");
+                    let expr = bin
+                        .clone()
+                        .eq(lit(i as i64))
+                        .cast(DataType::UInt8)
+                        .alias(column.as_str());
+                    (column, expr)
+                })
+                .collect(),
+        }
+    }
+
+    /// Learns the bin edges of every column.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), DiscretizationError> {
+        if self.n_bins < 2 {
+            return Err(DiscretizationError::InvalidParameterError(format!(
+                "n_bins must be at least 2, got {}",
+                self.n_bins
+            )));
+        }
+        validate_dataframe(df)?;
+
+        let bin_edges = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| Ok((name.to_string(), self.column_edges(df, name)?)))
+            .collect::<Result<Vec<_>, DiscretizationError>>()?;
+
+        self.bin_edges = Some(bin_edges);
+
+        Ok(())
+    }
+
+    /// Replaces the fitted columns with their bins. Other columns are passed through.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, DiscretizationError> {
+        let bin_edges = self
+            .bin_edges
+            .as_ref()
+            .ok_or(DiscretizationError::NotFittedError)?;
+
+        let fitted: Vec<&str> = bin_edges.iter().map(|(name, _)| name.as_str()).collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        let mut binned: Vec<Expr> = Vec::new();
+        let mut output: Vec<Expr> = Vec::new();
+        for name in df.get_column_names() {
+            match bin_edges.iter().find(|(fitted, _)| fitted == name.as_str()) {
+                Some((_, edges)) => {
+                    for (column, expr) in self.binned_columns(name, edges) {
+                        binned.push(expr);
+                        output.push(col(column));
+                    }
+                },
+                None => output.push(col(name.clone())),
+            }
+        }
+
+        // The bins are added with `with_columns`, so that columns without inner edges
+        // broadcast their constant bin to every row.
+        let binned_df = df
+            .clone()
+            .lazy()
+            .with_columns(binned)
+            .select(output)
+            .collect()?;
+
+        Ok(binned_df)
+    }
+
+    /// Learns the bin edges and returns the binned DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, DiscretizationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.bin_edges = None;
+    }
+}
+
+/// Runs Lloyd's algorithm on 1-D values, returning the sorted cluster centers.
+///
+/// A center that loses all of its values keeps its previous position. The iterations stop
+/// once no center moves by more than `tolerance`.
+fn kmeans_1d(values: &[f64], mut centers: Vec<f64>, tolerance: f64) -> Vec<f64> {
+    for _ in 0..KMEANS_MAX_ITER {
+        let mut sums = vec![0.0; centers.len()];
+        let mut counts = vec![0usize; centers.len()];
+
+        for &value in values {
+            let nearest = centers
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| (value - *a).abs().total_cmp(&(value - *b).abs()))
+                .map(|(i, _)| i)
+                .unwrap();
+            sums[nearest] += value;
+            counts[nearest] += 1;
+        }
+
+        let mut shift: f64 = 0.0;
+        for (i, center) in centers.iter_mut().enumerate() {
+            if counts[i] > 0 {
+                let updated = sums[i] / counts[i] as f64;
+                shift = shift.max((updated - *center).abs());
+                *center = updated;
+            }
+        }
+
+        if shift <= tolerance {
+            break;
+        }
+    }
+
+    centers.sort_by(f64::total_cmp);
+    centers
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "feature1" => [0.0f64, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+            "feature2" => [1.0f64, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 10.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let discretizer = KBinsDiscretizer::default();
+        assert_eq!(discretizer.n_bins(), 5);
+        assert_eq!(discretizer.strategy, BinStrategy::Quantile);
+        assert_eq!(discretizer.encoding, BinEncoding::Ordinal);
+        assert!(discretizer.bin_edges().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_uniform() {
+        let mut discretizer = KBinsDiscretizer::new(2).with_strategy(BinStrategy::Uniform);
+        discretizer.fit(&create_df()).unwrap();
+
+        let bin_edges = discretizer.bin_edges().unwrap();
+        assert_eq!(bin_edges[0].1, [0.0, 3.5, 7.0]);
+        assert_eq!(bin_edges[1].1, [1.0, 5.5, 10.0]);
+    }
+
+    #[test]
+    fn test_fit_quantile_merges_duplicate_edges() {
+        let mut discretizer = KBinsDiscretizer::new(4);
+        discretizer.fit(&create_df()).unwrap();
+
+        let bin_edges = discretizer.bin_edges().unwrap();
+        assert_eq!(bin_edges[0].1, [0.0, 1.75, 3.5, 5.25, 7.0]);
+        // The first three quantiles of feature2 are all 1.
+        assert_eq!(bin_edges[1].1, [1.0, 1.25, 10.0]);
+    }
+
+    #[test]
+    fn test_fit_kmeans() {
+        let df = df!["feature" => [0.0f64, 0.5, 1.0, 9.0, 10.0, 11.0]].unwrap();
+        let mut discretizer = KBinsDiscretizer::new(2).with_strategy(BinStrategy::KMeans);
+        discretizer.fit(&df).unwrap();
+
+        // The centers converge to 0.5 and 10, so the inner edge is their midpoint.
+        let edges = &discretizer.bin_edges().unwrap()[0].1;
+        assert_eq!(edges.len(), 3);
+        assert_abs_diff_eq!(edges[1], 5.25, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fit_tiny_scale() {
+        let df = df!["tiny" => [0.0f64, 0.5e-9, 1.5e-9, 2.5e-9, 3.5e-9, 4e-9]].unwrap();
+        let mut discretizer = KBinsDiscretizer::new(4).with_strategy(BinStrategy::Uniform);
+        let binned_df = discretizer.fit_transform(&df).unwrap();
+
+        assert_eq!(discretizer.bin_edges().unwrap()[0].1.len(), 5);
+        assert_eq!(binned_df, df!["tiny" => [0i64, 0, 1, 2, 3, 3]].unwrap());
+    }
+
+    #[test]
+    fn test_fit_constant_column() {
+        let df = df!["constant" => [3.0f64, 3.0, 3.0]].unwrap();
+        let mut discretizer = KBinsDiscretizer::new(3);
+        let binned_df = discretizer.fit_transform(&df).unwrap();
+
+        assert_eq!(
+            discretizer.bin_edges().unwrap()[0].1,
+            [f64::NEG_INFINITY, f64::INFINITY]
+        );
+        assert_eq!(binned_df, df!["constant" => [0i64, 0, 0]].unwrap());
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut discretizer = KBinsDiscretizer::new(1);
+        assert!(matches!(
+            discretizer.fit(&create_df()),
+            Err(DiscretizationError::InvalidParameterError(_))
+        ));
+
+        let df = df!["feature" => [Some(1.0f64), None]].unwrap();
+        let mut discretizer = KBinsDiscretizer::new(2);
+        assert!(matches!(
+            discretizer.fit(&df),
+            Err(DiscretizationError::ValidationError(
+                ValidationError::MissingValuesError(_)
+            ))
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_ordinal() {
+        let mut discretizer = KBinsDiscretizer::new(2).with_strategy(BinStrategy::Uniform);
+        discretizer.fit(&create_df()).unwrap();
+
+        let df = df![
+            "feature1" => [-1.0f64, 3.4, 3.5, 20.0],
+            "feature2" => [1.0f64, 5.0, 6.0, 10.0]
+        ]
+        .unwrap();
+        let expected = df![
+            "feature1" => [0i64, 0, 1, 1],
+            "feature2" => [0i64, 0, 1, 1]
+        ]
+        .unwrap();
+        assert_eq!(discretizer.transform(&df).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_transform_one_hot() {
+        let mut discretizer = KBinsDiscretizer::new(2)
+            .with_strategy(BinStrategy::Uniform)
+            .with_encoding(BinEncoding::OneHot);
+        discretizer
+            .fit(&create_df().select(["feature1"]).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            discretizer.feature_names_out().unwrap(),
+            ["feature1_0", "feature1_1"]
+        );
+
+        let df = df![
+            "feature1" => [1.0f64, 6.0],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        let expected = df![
+            "feature1_0" => [1u8, 0],
+            "feature1_1" => [0u8, 1],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        assert_eq!(discretizer.transform(&df).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_transform_missing_column() {
+        let mut discretizer = KBinsDiscretizer::new(2);
+        discretizer.fit(&create_df()).unwrap();
+
+        let df = create_df().select(["feature1"]).unwrap();
+        assert!(matches!(
+            discretizer.transform(&df),
+            Err(DiscretizationError::ValidationError(
+                ValidationError::MissingColumnsError(ref cols)
+            )) if cols == "feature2"
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let discretizer = KBinsDiscretizer::new(2);
+        assert!(matches!(
+            discretizer.transform(&create_df()),
+            Err(DiscretizationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut discretizer = KBinsDiscretizer::new(2);
+        discretizer.fit(&create_df()).unwrap();
+
+        discretizer.reset();
+        assert!(discretizer.bin_edges().is_none());
+    }
+}
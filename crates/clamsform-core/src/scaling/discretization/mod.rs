@@ -0,0 +1,3 @@
+pub mod binarizer;
+pub mod error;
+pub mod kbins;
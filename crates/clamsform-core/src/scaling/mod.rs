@@ -1,2 +1,3 @@
+pub mod discretization;
 pub mod standardization;
 pub mod traits;
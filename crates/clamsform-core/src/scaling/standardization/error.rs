@@ -5,6 +5,12 @@ use crate::validation::error::ValidationError;
 
 #[derive(Error, Debug)]
 pub enum ScalingError {
+    #[error(
+        "The scaler has not been fitted. \
+        Call `fit` before attempting to transform a DataFrame."
+    )]
+    NotFittedError,
+
     #[error(
         "The {0} in column(s) {1} is zero. \
         Division by zero is not allowed. \
@@ -43,17 +49,51 @@ macro_rules! scaling_err {
     };
 }
 
-pub fn validate_non_zero_denom(df: &DataFrame, metric: &str) -> Result<(), ScalingError> {
-    let zero_cols: Vec<String> = df
-        .get_columns()
-        .iter()
-        .filter_map(|col| match col.get(0).ok() {
-            Some(AnyValue::Float64(0.0)) => Some(col.name().to_string()),
-            Some(AnyValue::Float32(0.0)) => Some(col.name().to_string()),
-            _ => None,
-        })
+/// Thresholds used to decide whether a denominator is too close to zero.
+///
+/// A denominator is flagged as near zero when its magnitude is below the `absolute`
+/// threshold, or when a `relative` threshold is set and its magnitude is below
+/// `relative` times the magnitude of the corresponding column mean.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearZeroThresholds {
+    pub absolute: f64,
+    pub relative: Option<f64>,
+}
+
+impl Default for NearZeroThresholds {
+    fn default() -> Self {
+        NearZeroThresholds {
+            absolute: 1e-8,
+            relative: None,
+        }
+    }
+}
+
+/// Collects the values of a statistics column as `f64`, so that every row is checked.
+fn column_values(col: &Column) -> Result<Vec<Option<f64>>, ScalingError> {
+    let values = col
+        .as_materialized_series()
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
         .collect();
 
+    Ok(values)
+}
+
+pub fn validate_non_zero_denom(df: &DataFrame, metric: &str) -> Result<(), ScalingError> {
+    let mut zero_cols: Vec<String> = Vec::new();
+
+    for col in df.get_columns() {
+        if column_values(col)?
+            .into_iter()
+            .flatten()
+            .any(|val| val == 0.0)
+        {
+            zero_cols.push(col.name().to_string());
+        }
+    }
+
     if !zero_cols.is_empty() {
         return scaling_err!(zero_denom = metric, zero_cols);
     }
@@ -61,22 +101,47 @@ pub fn validate_non_zero_denom(df: &DataFrame, metric: &str) -> Result<(), Scali
     Ok(())
 }
 
-pub fn validate_near_zero_denom(df: &DataFrame, metric: &str) -> Result<(), ScalingError> {
-    const NEAR_ZERO_THRESHOLD: f64 = 1e-8;
-
-    let near_zero_cols: Vec<String> = df
-        .get_columns()
-        .iter()
-        .filter_map(|col| match col.get(0).ok() {
-            Some(AnyValue::Float64(val)) if val.abs() < NEAR_ZERO_THRESHOLD => {
-                Some(col.name().to_string())
-            },
-            Some(AnyValue::Float32(val)) if (val as f64).abs() < NEAR_ZERO_THRESHOLD => {
-                Some(col.name().to_string())
-            },
-            _ => None,
-        })
-        .collect();
+/// Validates that no denominator in the statistics frame is near zero.
+///
+/// # Arguments
+/// * `df` - The statistics frame holding the denominators, one row per group.
+/// * `means` - The matching frame of column means, used for the relative threshold.
+/// * `metric` - The name of the statistic, used in the error message.
+/// * `thresholds` - The absolute and relative near-zero thresholds.
+pub fn validate_near_zero_denom(
+    df: &DataFrame,
+    means: Option<&DataFrame>,
+    metric: &str,
+    thresholds: &NearZeroThresholds,
+) -> Result<(), ScalingError> {
+    let mut near_zero_cols: Vec<String> = Vec::new();
+
+    for col in df.get_columns() {
+        let denoms = column_values(col)?;
+        let scales = match (thresholds.relative, means) {
+            (Some(_), Some(means)) => column_values(means.column(col.name())?)?,
+            _ => vec![None; denoms.len()],
+        };
+
+        let is_near_zero = denoms
+            .iter()
+            .zip(scales.iter())
+            .any(|(denom, scale)| match denom {
+                Some(denom) => {
+                    let below_absolute = denom.abs() < thresholds.absolute;
+                    let below_relative = match (thresholds.relative, scale) {
+                        (Some(relative), Some(scale)) => denom.abs() < relative * scale.abs(),
+                        _ => false,
+                    };
+                    below_absolute || below_relative
+                },
+                None => false,
+            });
+
+        if is_near_zero {
+            near_zero_cols.push(col.name().to_string());
+        }
+    }
 
     if !near_zero_cols.is_empty() {
         return scaling_err!(near_zero_denom = metric, near_zero_cols);
@@ -85,9 +150,14 @@ pub fn validate_near_zero_denom(df: &DataFrame, metric: &str) -> Result<(), Scal
     Ok(())
 }
 
-pub fn validate_denoms(df: &DataFrame, metric: &str) -> Result<(), ScalingError> {
+pub fn validate_denoms(
+    df: &DataFrame,
+    means: Option<&DataFrame>,
+    metric: &str,
+    thresholds: &NearZeroThresholds,
+) -> Result<(), ScalingError> {
     validate_non_zero_denom(df, metric)?;
-    validate_near_zero_denom(df, metric)?;
+    validate_near_zero_denom(df, means, metric, thresholds)?;
 
     Ok(())
 }
@@ -125,26 +195,66 @@ mod tests {
         assert!(validate_non_zero_denom(&valid_df, "std").is_ok())
     }
 
+    #[test]
+    fn test_validate_non_zero_denom_all_rows() {
+        let grouped_df = df![
+            "std1" => [2.33f64, 0.0],
+            "std2" => [33.75f64, 1.5]
+        ]
+        .unwrap();
+        let result = validate_non_zero_denom(&grouped_df, "std");
+        assert!(matches!(
+            result,
+            Err(ScalingError::ZeroDenominatorError(_, ref cols)) if cols == "std1"
+        ));
+    }
+
     #[test]
     fn test_validate_near_zero_denom() {
+        let thresholds = NearZeroThresholds::default();
+
         let invalid_df = create_invalid_df();
-        let result = validate_near_zero_denom(&invalid_df, "std");
+        let result = validate_near_zero_denom(&invalid_df, None, "std", &thresholds);
         assert!(matches!(
             result,
             Err(ScalingError::NearZeroDenominatorError(..))
         ));
 
         let valid_df = create_valid_df();
-        assert!(validate_near_zero_denom(&valid_df, "std").is_ok())
+        assert!(validate_near_zero_denom(&valid_df, None, "std", &thresholds).is_ok())
+    }
+
+    #[test]
+    fn test_validate_near_zero_denom_relative() {
+        let stds = df!["tiny" => [2e-11f64], "huge" => [5.0f64]].unwrap();
+        let means = df!["tiny" => [1e-10f64], "huge" => [1e9f64]].unwrap();
+
+        let thresholds = NearZeroThresholds {
+            absolute: 0.0,
+            relative: Some(1e-3),
+        };
+        let result = validate_near_zero_denom(&stds, Some(&means), "std", &thresholds);
+        assert!(matches!(
+            result,
+            Err(ScalingError::NearZeroDenominatorError(_, ref cols)) if cols == "huge"
+        ));
+
+        let absolute_only = NearZeroThresholds {
+            absolute: 0.0,
+            relative: None,
+        };
+        assert!(validate_near_zero_denom(&stds, Some(&means), "std", &absolute_only).is_ok());
     }
 
     #[test]
     fn test_validate_stds() {
+        let thresholds = NearZeroThresholds::default();
+
         let invalid_df = create_invalid_df();
-        let result = validate_denoms(&invalid_df, "std");
+        let result = validate_denoms(&invalid_df, None, "std", &thresholds);
         assert!(result.is_err());
 
         let valid_df = create_valid_df();
-        assert!(validate_denoms(&valid_df, "std").is_ok());
+        assert!(validate_denoms(&valid_df, None, "std", &thresholds).is_ok());
     }
 }
@@ -0,0 +1,46 @@
+use polars::prelude::*;
+
+use super::error::ScalingError;
+use crate::validation::error::validate_columns_present;
+
+/// Builds one expression per fitted column from its learned statistics.
+///
+/// # Arguments
+/// * `df` - The DataFrame to transform.
+/// * `stats` - The single-row statistics frames, all holding the fitted columns.
+/// * `expr` - Builds the expression of a column from its statistics, in the order of `stats`.
+///
+/// # Returns
+/// * `Ok(Vec<Expr>)` with one expression per fitted column, aliased to the column name.
+/// * `Err(ValidationError::MissingColumnsError)` with a list of fitted columns absent from `df`.
+pub(crate) fn fitted_exprs(
+    df: &DataFrame,
+    stats: &[&DataFrame],
+    expr: impl Fn(Expr, &[f64]) -> Expr,
+) -> Result<Vec<Expr>, ScalingError> {
+    let Some(first) = stats.first() else {
+        return Ok(Vec::new());
+    };
+
+    let fitted: Vec<&str> = first
+        .get_column_names()
+        .into_iter()
+        .map(|name| name.as_str())
+        .collect();
+    validate_columns_present(df, &fitted)?;
+
+    first
+        .get_column_names()
+        .into_iter()
+        .map(|name| {
+            let values = stats
+                .iter()
+                .map(|stat| {
+                    let values = stat.column(name)?.cast(&DataType::Float64)?;
+                    Ok(values.f64()?.get(0).unwrap_or(f64::NAN))
+                })
+                .collect::<Result<Vec<f64>, ScalingError>>()?;
+            Ok(expr(col(name.as_str()), &values).alias(name.as_str()))
+        })
+        .collect()
+}
@@ -0,0 +1,206 @@
+use polars::prelude::*;
+
+use super::super::traits::FeatureScaler;
+use super::error::*;
+use super::fitted::*;
+use crate::transformation::error::TransformationError;
+use crate::transformation::traits;
+use crate::validation::error::*;
+
+/// Divides every column by its learned maximum absolute value, mapping it to `[-1, 1]`.
+///
+/// The values are not shifted, so zeros are preserved and sparse columns stay sparse.
+pub struct MaxAbsScaler {
+    max_abs: Option<DataFrame>,
+    near_zero_thresholds: NearZeroThresholds,
+}
+
+impl Default for MaxAbsScaler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaxAbsScaler {
+    pub fn new() -> Self {
+        MaxAbsScaler {
+            max_abs: None,
+            near_zero_thresholds: NearZeroThresholds::default(),
+        }
+    }
+
+    /// Sets the thresholds used to reject near-zero maximum absolute values.
+    ///
+    /// Only the absolute threshold applies, as the maximum absolute value always bounds the
+    /// magnitude of the column mean.
+    pub fn with_near_zero_thresholds(mut self, thresholds: NearZeroThresholds) -> Self {
+        self.near_zero_thresholds = thresholds;
+        self
+    }
+
+    pub fn max_abs(&self) -> Option<&DataFrame> {
+        self.max_abs.as_ref()
+    }
+
+    pub fn near_zero_thresholds(&self) -> &NearZeroThresholds {
+        &self.near_zero_thresholds
+    }
+}
+
+impl MaxAbsScaler {
+    /// Learns the maximum absolute value of every column, validating it.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), ScalingError> {
+        validate_dataframe(df)?;
+
+        let max_abs = df.clone().lazy().select([all().abs().max()]).collect()?;
+
+        validate_denoms(
+            &max_abs,
+            None,
+            "maximum absolute value",
+            &self.near_zero_thresholds,
+        )?;
+
+        self.max_abs = Some(max_abs);
+
+        Ok(())
+    }
+
+    /// Scales the fitted columns with the learned maximum absolute values.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let max_abs = self.max_abs.as_ref().ok_or(ScalingError::NotFittedError)?;
+
+        let exprs = fitted_exprs(df, &[max_abs], |expr, stats| expr / lit(stats[0]))?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+
+    /// Maps scaled values of the fitted columns back to their original scale.
+    pub fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let max_abs = self.max_abs.as_ref().ok_or(ScalingError::NotFittedError)?;
+
+        let exprs = fitted_exprs(df, &[max_abs], |expr, stats| expr * lit(stats[0]))?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+}
+
+impl FeatureScaler for MaxAbsScaler {
+    fn standardize(&mut self, df: &DataFrame, decimals: u32) -> Result<DataFrame, ScalingError> {
+        self.fit(df)?;
+
+        let scaled_df = self
+            .transform(df)?
+            .lazy()
+            .select([all().round(decimals)])
+            .collect()?;
+
+        Ok(scaled_df)
+    }
+
+    fn reset(&mut self) {
+        self.max_abs = None;
+    }
+}
+
+impl traits::InvertibleTransformer for MaxAbsScaler {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        Ok(MaxAbsScaler::fit(self, df)?)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(MaxAbsScaler::transform(self, df)?)
+    }
+
+    fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(MaxAbsScaler::inverse_transform(self, df)?)
+    }
+
+    fn reset(&mut self) {
+        FeatureScaler::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_valid_df() -> DataFrame {
+        df![
+            "feature1" => [0.0, 0.0, 2.0, -4.0, 0.0],
+            "feature2" => [1.0, 2.0, 3.0, 4.0, 8.0]
+        ]
+        .unwrap()
+    }
+
+    fn create_scaled_df() -> DataFrame {
+        df![
+            "feature1" => [0.0, 0.0, 0.5, -1.0, 0.0],
+            "feature2" => [0.125, 0.25, 0.375, 0.5, 1.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let scaler = MaxAbsScaler::new();
+        assert!(scaler.max_abs().is_none());
+    }
+
+    // Test standardization method
+    #[test]
+    fn test_standardization_method() {
+        let mut scaler = MaxAbsScaler::new();
+        let scaled_df = scaler.standardize(&create_valid_df(), 3).unwrap();
+
+        assert_eq!(scaled_df, create_scaled_df());
+        assert_eq!(
+            scaler.max_abs().unwrap(),
+            &df!["feature1" => [4.0], "feature2" => [8.0]].unwrap()
+        );
+    }
+
+    #[test]
+    fn test_standardization_method_all_zeros() {
+        let df = df!["zeros" => [0.0, 0.0, 0.0]].unwrap();
+        let mut scaler = MaxAbsScaler::new();
+        assert!(matches!(
+            scaler.standardize(&df, 2),
+            Err(ScalingError::ZeroDenominatorError(_, ref cols)) if cols == "zeros"
+        ));
+    }
+
+    // Test fit, transform and inverse_transform methods
+    #[test]
+    fn test_inverse_transform_method() {
+        let mut scaler = MaxAbsScaler::new();
+        scaler.fit(&create_valid_df()).unwrap();
+
+        let scaled_df = scaler.transform(&create_valid_df()).unwrap();
+        assert_eq!(scaled_df, create_scaled_df());
+        assert_eq!(
+            scaler.inverse_transform(&scaled_df).unwrap(),
+            create_valid_df()
+        );
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let scaler = MaxAbsScaler::new();
+        assert!(matches!(
+            scaler.transform(&create_valid_df()),
+            Err(ScalingError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut scaler = MaxAbsScaler::new();
+        scaler.fit(&create_valid_df()).unwrap();
+
+        scaler.reset();
+        assert!(scaler.max_abs().is_none());
+    }
+}
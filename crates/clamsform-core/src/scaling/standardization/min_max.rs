@@ -0,0 +1,254 @@
+use polars::prelude::*;
+
+use super::super::traits::FeatureScaler;
+use super::error::*;
+use super::fitted::*;
+use crate::transformation::error::TransformationError;
+use crate::transformation::traits;
+use crate::validation::error::*;
+
+/// Rescales every column to the `[0, 1]` range with its learned minimum and maximum.
+pub struct MinMaxScaler {
+    min: Option<DataFrame>,
+    max: Option<DataFrame>,
+    near_zero_thresholds: NearZeroThresholds,
+}
+
+impl Default for MinMaxScaler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MinMaxScaler {
+    pub fn new() -> Self {
+        MinMaxScaler {
+            min: None,
+            max: None,
+            near_zero_thresholds: NearZeroThresholds::default(),
+        }
+    }
+
+    /// Sets the thresholds used to reject near-zero ranges.
+    pub fn with_near_zero_thresholds(mut self, thresholds: NearZeroThresholds) -> Self {
+        self.near_zero_thresholds = thresholds;
+        self
+    }
+
+    pub fn min(&self) -> Option<&DataFrame> {
+        self.min.as_ref()
+    }
+
+    pub fn max(&self) -> Option<&DataFrame> {
+        self.max.as_ref()
+    }
+
+    pub fn near_zero_thresholds(&self) -> &NearZeroThresholds {
+        &self.near_zero_thresholds
+    }
+}
+
+impl MinMaxScaler {
+    /// Learns the minimum and maximum of every column, validating the ranges.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), ScalingError> {
+        validate_dataframe(df)?;
+
+        let min = df.clone().lazy().select([all().min()]).collect()?;
+        let max = df.clone().lazy().select([all().max()]).collect()?;
+        let range = df
+            .clone()
+            .lazy()
+            .select([all().max() - all().min()])
+            .collect()?;
+        let mean = df.clone().lazy().select([all().mean()]).collect()?;
+
+        validate_denoms(&range, Some(&mean), "range", &self.near_zero_thresholds)?;
+
+        self.min = Some(min);
+        self.max = Some(max);
+
+        Ok(())
+    }
+
+    /// Rescales the fitted columns with the learned minimum and maximum.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let (min, max) = self
+            .min
+            .as_ref()
+            .zip(self.max.as_ref())
+            .ok_or(ScalingError::NotFittedError)?;
+
+        let exprs = fitted_exprs(df, &[min, max], |expr, stats| {
+            (expr - lit(stats[0])) / lit(stats[1] - stats[0])
+        })?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+
+    /// Maps rescaled values of the fitted columns back to their original scale.
+    pub fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let (min, max) = self
+            .min
+            .as_ref()
+            .zip(self.max.as_ref())
+            .ok_or(ScalingError::NotFittedError)?;
+
+        let exprs = fitted_exprs(df, &[min, max], |expr, stats| {
+            expr * lit(stats[1] - stats[0]) + lit(stats[0])
+        })?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+}
+
+impl FeatureScaler for MinMaxScaler {
+    fn standardize(&mut self, df: &DataFrame, decimals: u32) -> Result<DataFrame, ScalingError> {
+        self.fit(df)?;
+
+        let scaled_df = self
+            .transform(df)?
+            .lazy()
+            .select([all().round(decimals)])
+            .collect()?;
+
+        Ok(scaled_df)
+    }
+
+    fn reset(&mut self) {
+        self.min = None;
+        self.max = None;
+    }
+}
+
+impl traits::InvertibleTransformer for MinMaxScaler {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        Ok(MinMaxScaler::fit(self, df)?)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(MinMaxScaler::transform(self, df)?)
+    }
+
+    fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(MinMaxScaler::inverse_transform(self, df)?)
+    }
+
+    fn reset(&mut self) {
+        FeatureScaler::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_valid_df() -> DataFrame {
+        df![
+            "feature1" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "feature2" => [-10.0, 0.0, 10.0, 20.0, 30.0]
+        ]
+        .unwrap()
+    }
+
+    fn create_scaled_df() -> DataFrame {
+        df![
+            "feature1" => [0.0, 0.25, 0.5, 0.75, 1.0],
+            "feature2" => [0.0, 0.25, 0.5, 0.75, 1.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let scaler = MinMaxScaler::new();
+        assert!(scaler.min().is_none());
+        assert!(scaler.max().is_none());
+    }
+
+    // Test standardization method
+    #[test]
+    fn test_standardization_method() {
+        let mut scaler = MinMaxScaler::new();
+        let scaled_df = scaler.standardize(&create_valid_df(), 2).unwrap();
+
+        assert_eq!(scaled_df, create_scaled_df());
+        assert_eq!(
+            scaler.min().unwrap(),
+            &df!["feature1" => [1.0], "feature2" => [-10.0]].unwrap()
+        );
+        assert_eq!(
+            scaler.max().unwrap(),
+            &df!["feature1" => [5.0], "feature2" => [30.0]].unwrap()
+        );
+    }
+
+    #[test]
+    fn test_standardization_method_zero_range() {
+        let df = df!["constant" => [2.0, 2.0, 2.0]].unwrap();
+        let mut scaler = MinMaxScaler::new();
+        assert!(matches!(
+            scaler.standardize(&df, 2),
+            Err(ScalingError::ZeroDenominatorError(..))
+        ));
+    }
+
+    #[test]
+    fn test_standardization_method_tiny_scale() {
+        let df = df!["tiny" => [1e-4, 2e-4, 3e-4, 4e-4, 5e-4]].unwrap();
+        let mut scaler = MinMaxScaler::new().with_near_zero_thresholds(NearZeroThresholds {
+            absolute: 0.0,
+            relative: None,
+        });
+        let scaled_df = scaler.standardize(&df, 2).unwrap();
+        assert_eq!(
+            scaled_df,
+            df!["tiny" => [0.0, 0.25, 0.5, 0.75, 1.0]].unwrap()
+        );
+
+        let restored_df = scaler.inverse_transform(&scaled_df).unwrap();
+        let restored = restored_df.column("tiny").unwrap().f64().unwrap();
+        for (restored, original) in restored
+            .into_no_null_iter()
+            .zip([1e-4, 2e-4, 3e-4, 4e-4, 5e-4])
+        {
+            assert_abs_diff_eq!(restored, original, epsilon = 1e-15);
+        }
+    }
+
+    // Test fit, transform and inverse_transform methods
+    #[test]
+    fn test_inverse_transform_method() {
+        let mut scaler = MinMaxScaler::new();
+        scaler.fit(&create_valid_df()).unwrap();
+
+        let scaled_df = scaler.transform(&create_valid_df()).unwrap();
+        assert_eq!(scaled_df, create_scaled_df());
+        assert_eq!(
+            scaler.inverse_transform(&scaled_df).unwrap(),
+            create_valid_df()
+        );
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let scaler = MinMaxScaler::new();
+        assert!(matches!(
+            scaler.transform(&create_valid_df()),
+            Err(ScalingError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut scaler = MinMaxScaler::new();
+        scaler.fit(&create_valid_df()).unwrap();
+
+        scaler.reset();
+        assert!(scaler.min().is_none());
+        assert!(scaler.max().is_none());
+    }
+}
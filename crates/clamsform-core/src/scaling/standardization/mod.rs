@@ -1,2 +1,7 @@
 pub mod error;
+pub mod fitted;
+pub mod max_abs;
+pub mod min_max;
+pub mod power;
+pub mod robust;
 pub mod z_score;
@@ -0,0 +1,397 @@
+use polars::prelude::*;
+
+use super::super::traits::FeatureScaler;
+use super::error::*;
+use super::fitted::*;
+use crate::transformation::error::TransformationError;
+use crate::transformation::traits;
+use crate::validation::error::*;
+
+/// The interval searched for the Yeo-Johnson lambda of every column.
+const LAMBDA_BOUNDS: (f64, f64) = (-5.0, 5.0);
+
+/// The width of the lambda interval at which the search stops.
+const LAMBDA_TOLERANCE: f64 = 1e-8;
+
+/// Lambdas closer than this to 0 or 2 use the logarithmic limit of the transformation.
+const LAMBDA_EPSILON: f64 = 1e-12;
+
+/// Applies the Yeo-Johnson transformation with `lambda` to a single value.
+fn yeo_johnson(x: f64, lambda: f64) -> f64 {
+    if x >= 0.0 {
+        if lambda.abs() < LAMBDA_EPSILON {
+            x.ln_1p()
+        } else {
+            ((x + 1.0).powf(lambda) - 1.0) / lambda
+        }
+    } else if (lambda - 2.0).abs() < LAMBDA_EPSILON {
+        -(-x).ln_1p()
+    } else {
+        -((1.0 - x).powf(2.0 - lambda) - 1.0) / (2.0 - lambda)
+    }
+}
+
+/// Builds the Yeo-Johnson transformation with `lambda` of a column expression.
+fn yeo_johnson_expr(expr: Expr, lambda: f64) -> Expr {
+    let expr = expr.cast(DataType::Float64);
+    let positive = if lambda.abs() < LAMBDA_EPSILON {
+        expr.clone().log1p()
+    } else {
+        ((expr.clone() + lit(1.0)).pow(lambda) - lit(1.0)) / lit(lambda)
+    };
+    let negative = if (lambda - 2.0).abs() < LAMBDA_EPSILON {
+        -(-expr.clone()).log1p()
+    } else {
+        -((lit(1.0) - expr.clone()).pow(2.0 - lambda) - lit(1.0)) / lit(2.0 - lambda)
+    };
+
+    when(expr.gt_eq(lit(0.0)))
+        .then(positive)
+        .otherwise(negative)
+}
+
+/// Builds the inverse of the Yeo-Johnson transformation with `lambda` of a column expression.
+fn yeo_johnson_inverse_expr(expr: Expr, lambda: f64) -> Expr {
+    let expr = expr.cast(DataType::Float64);
+    let positive = if lambda.abs() < LAMBDA_EPSILON {
+        expr.clone().exp() - lit(1.0)
+    } else {
+        (expr.clone() * lit(lambda) + lit(1.0)).pow(1.0 / lambda) - lit(1.0)
+    };
+    let negative = if (lambda - 2.0).abs() < LAMBDA_EPSILON {
+        lit(1.0) - (-expr.clone()).exp()
+    } else {
+        lit(1.0) - (lit(lambda - 2.0) * expr.clone() + lit(1.0)).pow(1.0 / (2.0 - lambda))
+    };
+
+    when(expr.gt_eq(lit(0.0)))
+        .then(positive)
+        .otherwise(negative)
+}
+
+/// The Yeo-Johnson log-likelihood of `lambda`, up to a constant, assuming the transformed
+/// values are normally distributed.
+fn log_likelihood(values: &[f64], lambda: f64) -> f64 {
+    let n = values.len() as f64;
+    let transformed: Vec<f64> = values.iter().map(|x| yeo_johnson(*x, lambda)).collect();
+    let mean = transformed.iter().sum::<f64>() / n;
+    let variance = transformed.iter().map(|y| (y - mean).powi(2)).sum::<f64>() / n;
+    let jacobian: f64 = values.iter().map(|x| x.signum() * x.abs().ln_1p()).sum();
+
+    -n / 2.0 * variance.ln() + (lambda - 1.0) * jacobian
+}
+
+/// Finds the lambda maximizing the log-likelihood with a golden-section search.
+fn estimate_lambda(values: &[f64]) -> f64 {
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = LAMBDA_BOUNDS;
+    let mut left = high - ratio * (high - low);
+    let mut right = low + ratio * (high - low);
+    let mut left_value = log_likelihood(values, left);
+    let mut right_value = log_likelihood(values, right);
+
+    while high - low > LAMBDA_TOLERANCE {
+        // A NaN likelihood (overflow at extreme lambdas) moves the search away from it.
+        if left_value >= right_value || right_value.is_nan() {
+            high = right;
+            right = left;
+            right_value = left_value;
+            left = high - ratio * (high - low);
+            left_value = log_likelihood(values, left);
+        } else {
+            low = left;
+            left = right;
+            left_value = right_value;
+            right = low + ratio * (high - low);
+            right_value = log_likelihood(values, right);
+        }
+    }
+
+    (low + high) / 2.0
+}
+
+/// Makes every column more Gaussian-like with a Yeo-Johnson power transformation, then
+/// standardizes it to zero mean and unit variance.
+///
+/// The lambda of every column is estimated by maximum likelihood. Unlike the Box-Cox
+/// transformation, Yeo-Johnson is defined for zero and negative values.
+pub struct PowerTransformer {
+    lambdas: Option<DataFrame>,
+    mean: Option<DataFrame>,
+    std: Option<DataFrame>,
+    near_zero_thresholds: NearZeroThresholds,
+}
+
+impl Default for PowerTransformer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PowerTransformer {
+    pub fn new() -> Self {
+        PowerTransformer {
+            lambdas: None,
+            mean: None,
+            std: None,
+            near_zero_thresholds: NearZeroThresholds::default(),
+        }
+    }
+
+    /// Sets the thresholds used to reject near-zero standard deviations of the transformed
+    /// columns.
+    pub fn with_near_zero_thresholds(mut self, thresholds: NearZeroThresholds) -> Self {
+        self.near_zero_thresholds = thresholds;
+        self
+    }
+
+    /// The Yeo-Johnson lambda of every fitted column.
+    pub fn lambdas(&self) -> Option<&DataFrame> {
+        self.lambdas.as_ref()
+    }
+
+    /// The mean of every fitted column after the power transformation.
+    pub fn mean(&self) -> Option<&DataFrame> {
+        self.mean.as_ref()
+    }
+
+    /// The standard deviation of every fitted column after the power transformation.
+    pub fn std(&self) -> Option<&DataFrame> {
+        self.std.as_ref()
+    }
+
+    pub fn near_zero_thresholds(&self) -> &NearZeroThresholds {
+        &self.near_zero_thresholds
+    }
+}
+
+impl PowerTransformer {
+    /// Estimates the lambda of every column, then learns the mean and standard deviation of
+    /// the transformed columns.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), ScalingError> {
+        validate_dataframe(df)?;
+
+        let lambdas = df
+            .get_columns()
+            .iter()
+            .map(|column| {
+                let values = column.cast(&DataType::Float64)?;
+                let values: Vec<f64> = values.f64()?.into_no_null_iter().collect();
+                Ok(Column::new(
+                    column.name().clone(),
+                    [estimate_lambda(&values)],
+                ))
+            })
+            .collect::<Result<Vec<Column>, ScalingError>>()?;
+        let lambdas = DataFrame::new(lambdas)?;
+
+        let transformed = df
+            .clone()
+            .lazy()
+            .select(fitted_exprs(df, &[&lambdas], |expr, stats| {
+                yeo_johnson_expr(expr, stats[0])
+            })?)
+            .collect()?;
+        let mean = transformed
+            .clone()
+            .lazy()
+            .select([all().mean()])
+            .collect()?;
+        let std = transformed.lazy().select([all().std(1)]).collect()?;
+
+        validate_denoms(
+            &std,
+            Some(&mean),
+            "standard deviation",
+            &self.near_zero_thresholds,
+        )?;
+
+        self.lambdas = Some(lambdas);
+        self.mean = Some(mean);
+        self.std = Some(std);
+
+        Ok(())
+    }
+
+    /// Applies the learned power transformation and standardization to the fitted columns.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let (lambdas, mean, std) = self.fitted()?;
+
+        let exprs = fitted_exprs(df, &[lambdas, mean, std], |expr, stats| {
+            (yeo_johnson_expr(expr, stats[0]) - lit(stats[1])) / lit(stats[2])
+        })?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+
+    /// Maps transformed values of the fitted columns back to their original scale.
+    pub fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let (lambdas, mean, std) = self.fitted()?;
+
+        let exprs = fitted_exprs(df, &[lambdas, mean, std], |expr, stats| {
+            yeo_johnson_inverse_expr(expr * lit(stats[2]) + lit(stats[1]), stats[0])
+        })?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+
+    fn fitted(&self) -> Result<(&DataFrame, &DataFrame, &DataFrame), ScalingError> {
+        match (&self.lambdas, &self.mean, &self.std) {
+            (Some(lambdas), Some(mean), Some(std)) => Ok((lambdas, mean, std)),
+            _ => Err(ScalingError::NotFittedError),
+        }
+    }
+}
+
+impl FeatureScaler for PowerTransformer {
+    fn standardize(&mut self, df: &DataFrame, decimals: u32) -> Result<DataFrame, ScalingError> {
+        self.fit(df)?;
+
+        let transformed_df = self
+            .transform(df)?
+            .lazy()
+            .select([all().round(decimals)])
+            .collect()?;
+
+        Ok(transformed_df)
+    }
+
+    fn reset(&mut self) {
+        self.lambdas = None;
+        self.mean = None;
+        self.std = None;
+    }
+}
+
+impl traits::InvertibleTransformer for PowerTransformer {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        Ok(PowerTransformer::fit(self, df)?)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(PowerTransformer::transform(self, df)?)
+    }
+
+    fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(PowerTransformer::inverse_transform(self, df)?)
+    }
+
+    fn reset(&mut self) {
+        FeatureScaler::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_valid_df() -> DataFrame {
+        df![
+            "skewed" => [1.0, 1.0, 2.0, 2.0, 3.0, 4.0, 6.0, 9.0, 14.0, 22.0],
+            "negative" => [-3.0, -1.5, -1.0, -0.5, 0.0, 0.0, 0.5, 1.0, 2.5, 4.0]
+        ]
+        .unwrap()
+    }
+
+    fn column_values(df: &DataFrame, name: &str) -> Vec<f64> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    // Test Yeo-Johnson helpers
+    #[test]
+    fn test_yeo_johnson() {
+        assert_abs_diff_eq!(yeo_johnson(3.0, 1.0), 3.0);
+        assert_abs_diff_eq!(yeo_johnson(-3.0, 1.0), -3.0);
+        assert_abs_diff_eq!(yeo_johnson(3.0, 0.0), 4f64.ln());
+        assert_abs_diff_eq!(yeo_johnson(-3.0, 2.0), -(4f64.ln()));
+        assert_abs_diff_eq!(yeo_johnson(3.0, 0.5), 2.0);
+    }
+
+    #[test]
+    fn test_estimate_lambda() {
+        let values = column_values(&create_valid_df(), "skewed");
+        let lambda = estimate_lambda(&values);
+        assert!(lambda < 1.0);
+
+        let likelihood = log_likelihood(&values, lambda);
+        assert!(likelihood >= log_likelihood(&values, lambda - 0.01));
+        assert!(likelihood >= log_likelihood(&values, lambda + 0.01));
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let transformer = PowerTransformer::new();
+        assert!(transformer.lambdas().is_none());
+        assert!(transformer.mean().is_none());
+        assert!(transformer.std().is_none());
+    }
+
+    // Test standardization method
+    #[test]
+    fn test_standardization_method() {
+        let mut transformer = PowerTransformer::new();
+        let transformed_df = transformer.standardize(&create_valid_df(), 8).unwrap();
+
+        for name in ["skewed", "negative"] {
+            let values = column_values(&transformed_df, name);
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            let variance =
+                values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+            assert_abs_diff_eq!(mean, 0.0, epsilon = 1e-6);
+            assert_abs_diff_eq!(variance, 1.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_standardization_method_constant() {
+        let df = df!["constant" => [2.0, 2.0, 2.0]].unwrap();
+        let mut transformer = PowerTransformer::new();
+        assert!(transformer.standardize(&df, 2).is_err());
+    }
+
+    // Test fit, transform and inverse_transform methods
+    #[test]
+    fn test_inverse_transform_method() {
+        let mut transformer = PowerTransformer::new();
+        transformer.fit(&create_valid_df()).unwrap();
+
+        let transformed_df = transformer.transform(&create_valid_df()).unwrap();
+        let restored_df = transformer.inverse_transform(&transformed_df).unwrap();
+        for name in ["skewed", "negative"] {
+            let restored = column_values(&restored_df, name);
+            let original = column_values(&create_valid_df(), name);
+            for (restored, original) in restored.iter().zip(original.iter()) {
+                assert_abs_diff_eq!(restored, original, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let transformer = PowerTransformer::new();
+        assert!(matches!(
+            transformer.transform(&create_valid_df()),
+            Err(ScalingError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut transformer = PowerTransformer::new();
+        transformer.fit(&create_valid_df()).unwrap();
+
+        transformer.reset();
+        assert!(transformer.lambdas().is_none());
+        assert!(transformer.mean().is_none());
+        assert!(transformer.std().is_none());
+    }
+}
@@ -0,0 +1,237 @@
+use polars::prelude::*;
+
+use super::super::traits::FeatureScaler;
+use super::error::*;
+use super::fitted::*;
+use crate::transformation::error::TransformationError;
+use crate::transformation::traits;
+use crate::validation::error::*;
+
+/// Centers every column on its median and scales it by its interquartile range (IQR).
+///
+/// The median and quartiles are barely moved by outliers, unlike the mean and standard
+/// deviation used by the Z-score transformation.
+pub struct RobustScaler {
+    median: Option<DataFrame>,
+    iqr: Option<DataFrame>,
+    near_zero_thresholds: NearZeroThresholds,
+}
+
+impl Default for RobustScaler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RobustScaler {
+    pub fn new() -> Self {
+        RobustScaler {
+            median: None,
+            iqr: None,
+            near_zero_thresholds: NearZeroThresholds::default(),
+        }
+    }
+
+    /// Sets the thresholds used to reject near-zero interquartile ranges.
+    pub fn with_near_zero_thresholds(mut self, thresholds: NearZeroThresholds) -> Self {
+        self.near_zero_thresholds = thresholds;
+        self
+    }
+
+    pub fn median(&self) -> Option<&DataFrame> {
+        self.median.as_ref()
+    }
+
+    pub fn iqr(&self) -> Option<&DataFrame> {
+        self.iqr.as_ref()
+    }
+
+    pub fn near_zero_thresholds(&self) -> &NearZeroThresholds {
+        &self.near_zero_thresholds
+    }
+}
+
+impl RobustScaler {
+    /// Learns the median and interquartile range of every column, validating the ranges.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), ScalingError> {
+        validate_dataframe(df)?;
+
+        let quartile = |q: f64| all().quantile(lit(q), QuantileMethod::Linear);
+        let median = df.clone().lazy().select([all().median()]).collect()?;
+        let iqr = df
+            .clone()
+            .lazy()
+            .select([quartile(0.75) - quartile(0.25)])
+            .collect()?;
+        let mean = df.clone().lazy().select([all().mean()]).collect()?;
+
+        validate_denoms(
+            &iqr,
+            Some(&mean),
+            "interquartile range",
+            &self.near_zero_thresholds,
+        )?;
+
+        self.median = Some(median);
+        self.iqr = Some(iqr);
+
+        Ok(())
+    }
+
+    /// Scales the fitted columns with the learned median and interquartile range.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let (median, iqr) = self
+            .median
+            .as_ref()
+            .zip(self.iqr.as_ref())
+            .ok_or(ScalingError::NotFittedError)?;
+
+        let exprs = fitted_exprs(df, &[median, iqr], |expr, stats| {
+            (expr - lit(stats[0])) / lit(stats[1])
+        })?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+
+    /// Maps scaled values of the fitted columns back to their original scale.
+    pub fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let (median, iqr) = self
+            .median
+            .as_ref()
+            .zip(self.iqr.as_ref())
+            .ok_or(ScalingError::NotFittedError)?;
+
+        let exprs = fitted_exprs(df, &[median, iqr], |expr, stats| {
+            expr * lit(stats[1]) + lit(stats[0])
+        })?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+}
+
+impl FeatureScaler for RobustScaler {
+    fn standardize(&mut self, df: &DataFrame, decimals: u32) -> Result<DataFrame, ScalingError> {
+        self.fit(df)?;
+
+        let scaled_df = self
+            .transform(df)?
+            .lazy()
+            .select([all().round(decimals)])
+            .collect()?;
+
+        Ok(scaled_df)
+    }
+
+    fn reset(&mut self) {
+        self.median = None;
+        self.iqr = None;
+    }
+}
+
+impl traits::InvertibleTransformer for RobustScaler {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        Ok(RobustScaler::fit(self, df)?)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(RobustScaler::transform(self, df)?)
+    }
+
+    fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(RobustScaler::inverse_transform(self, df)?)
+    }
+
+    fn reset(&mut self) {
+        FeatureScaler::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_valid_df() -> DataFrame {
+        df![
+            "feature1" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "outliers" => [1.0, 2.0, 3.0, 4.0, 100.0]
+        ]
+        .unwrap()
+    }
+
+    fn create_scaled_df() -> DataFrame {
+        df![
+            "feature1" => [-1.0, -0.5, 0.0, 0.5, 1.0],
+            "outliers" => [-1.0, -0.5, 0.0, 0.5, 48.5]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let scaler = RobustScaler::new();
+        assert!(scaler.median().is_none());
+        assert!(scaler.iqr().is_none());
+    }
+
+    // Test standardization method
+    #[test]
+    fn test_standardization_method() {
+        let mut scaler = RobustScaler::new();
+        let scaled_df = scaler.standardize(&create_valid_df(), 2).unwrap();
+
+        assert_eq!(scaled_df, create_scaled_df());
+        assert_eq!(
+            scaler.median().unwrap(),
+            &df!["feature1" => [3.0], "outliers" => [3.0]].unwrap()
+        );
+        assert_eq!(
+            scaler.iqr().unwrap(),
+            &df!["feature1" => [2.0], "outliers" => [2.0]].unwrap()
+        );
+    }
+
+    #[test]
+    fn test_standardization_method_zero_iqr() {
+        let df = df!["spiky" => [1.0, 1.0, 1.0, 1.0, 9.0]].unwrap();
+        let mut scaler = RobustScaler::new();
+        assert!(matches!(
+            scaler.standardize(&df, 2),
+            Err(ScalingError::ZeroDenominatorError(_, ref cols)) if cols == "spiky"
+        ));
+    }
+
+    // Test fit, transform and inverse_transform methods
+    #[test]
+    fn test_inverse_transform_method() {
+        let mut scaler = RobustScaler::new();
+        scaler.fit(&create_valid_df()).unwrap();
+
+        let scaled_df = scaler.transform(&create_valid_df()).unwrap();
+        assert_eq!(scaled_df, create_scaled_df());
+        assert_eq!(
+            scaler.inverse_transform(&scaled_df).unwrap(),
+            create_valid_df()
+        );
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let scaler = RobustScaler::new();
+        assert!(matches!(
+            scaler.transform(&create_valid_df()),
+            Err(ScalingError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut scaler = RobustScaler::new();
+        scaler.fit(&create_valid_df()).unwrap();
+
+        scaler.reset();
+        assert!(scaler.median().is_none());
+        assert!(scaler.iqr().is_none());
+    }
+}
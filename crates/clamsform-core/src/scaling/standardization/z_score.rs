@@ -2,11 +2,15 @@ use polars::prelude::*;
 
 use super::super::traits::FeatureScaler;
 use super::error::*;
+use super::fitted::*;
+use crate::transformation::error::TransformationError;
+use crate::transformation::traits;
 use crate::validation::error::*;
 
 pub struct ZScoreScaler {
     mean: Option<DataFrame>,
     std: Option<DataFrame>,
+    near_zero_thresholds: NearZeroThresholds,
 }
 
 impl Default for ZScoreScaler {
@@ -20,9 +24,16 @@ impl ZScoreScaler {
         ZScoreScaler {
             mean: None,
             std: None,
+            near_zero_thresholds: NearZeroThresholds::default(),
         }
     }
 
+    /// Sets the thresholds used to reject near-zero standard deviations.
+    pub fn with_near_zero_thresholds(mut self, thresholds: NearZeroThresholds) -> Self {
+        self.near_zero_thresholds = thresholds;
+        self
+    }
+
     pub fn mean(&self) -> Option<&DataFrame> {
         self.mean.as_ref()
     }
@@ -30,31 +41,73 @@ impl ZScoreScaler {
     pub fn std(&self) -> Option<&DataFrame> {
         self.std.as_ref()
     }
+
+    pub fn near_zero_thresholds(&self) -> &NearZeroThresholds {
+        &self.near_zero_thresholds
+    }
 }
 
-impl FeatureScaler for ZScoreScaler {
-    fn standardize(&mut self, df: &DataFrame, decimals: u32) -> Result<DataFrame, ScalingError> {
+impl ZScoreScaler {
+    /// Learns the mean and standard deviation of every column without transforming it.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), ScalingError> {
         validate_dataframe(df)?;
 
-        self.mean = Some(
-            df.clone()
-                .lazy()
-                .select([all().mean().round(decimals)])
-                .collect()?,
-        );
-        self.std = Some(
-            df.clone()
-                .lazy()
-                .select([all().std(1).round(decimals)])
-                .collect()?,
-        );
+        let mean = df.clone().lazy().select([all().mean()]).collect()?;
+        let std = df.clone().lazy().select([all().std(1)]).collect()?;
 
-        validate_denoms(self.std.as_ref().unwrap(), "standard deviation")?;
+        validate_denoms(
+            &std,
+            Some(&mean),
+            "standard deviation",
+            &self.near_zero_thresholds,
+        )?;
 
-        let standardized_df = df
-            .clone()
+        self.mean = Some(mean);
+        self.std = Some(std);
+
+        Ok(())
+    }
+
+    /// Builds one expression per fitted column from its mean and standard deviation.
+    fn fitted_exprs(
+        &self,
+        df: &DataFrame,
+        expr: impl Fn(Expr, f64, f64) -> Expr,
+    ) -> Result<Vec<Expr>, ScalingError> {
+        let (mean, std) = self
+            .mean
+            .as_ref()
+            .zip(self.std.as_ref())
+            .ok_or(ScalingError::NotFittedError)?;
+
+        fitted_exprs(df, &[mean, std], |column, stats| {
+            expr(column, stats[0], stats[1])
+        })
+    }
+
+    /// Standardizes the fitted columns with the learned mean and standard deviation.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let exprs = self.fitted_exprs(df, |expr, mean, std| (expr - lit(mean)) / lit(std))?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+
+    /// Maps standardized values of the fitted columns back to their original scale.
+    pub fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, ScalingError> {
+        let exprs = self.fitted_exprs(df, |expr, mean, std| expr * lit(std) + lit(mean))?;
+
+        Ok(df.clone().lazy().with_columns(exprs).collect()?)
+    }
+}
+
+impl FeatureScaler for ZScoreScaler {
+    fn standardize(&mut self, df: &DataFrame, decimals: u32) -> Result<DataFrame, ScalingError> {
+        self.fit(df)?;
+
+        let standardized_df = self
+            .transform(df)?
             .lazy()
-            .select([((all() - all().mean()) / all().std(1)).round(decimals)])
+            .select([all().round(decimals)])
             .collect()?;
 
         Ok(standardized_df)
@@ -66,8 +119,28 @@ impl FeatureScaler for ZScoreScaler {
     }
 }
 
+impl traits::InvertibleTransformer for ZScoreScaler {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        Ok(ZScoreScaler::fit(self, df)?)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(ZScoreScaler::transform(self, df)?)
+    }
+
+    fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        Ok(ZScoreScaler::inverse_transform(self, df)?)
+    }
+
+    fn reset(&mut self) {
+        FeatureScaler::reset(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use approx::assert_abs_diff_eq;
+
     use super::*;
 
     // Test constructor and default
@@ -155,9 +228,16 @@ mod tests {
             .standardize(&valid_df, 2)
             .expect("Standardization failed");
 
-        let actual_std_df = z_score_scaler.std.as_ref().unwrap();
+        let actual_std_df = z_score_scaler
+            .std
+            .clone()
+            .unwrap()
+            .lazy()
+            .select([all().round(2)])
+            .collect()
+            .unwrap();
 
-        assert_eq!(*actual_std_df, expected_std_df);
+        assert_eq!(actual_std_df, expected_std_df);
     }
 
     #[test]
@@ -174,6 +254,115 @@ mod tests {
         assert_eq!(actual_standardized_df, expected_standardized_df);
     }
 
+    #[test]
+    fn test_standardization_method_relative_threshold() {
+        let valid_df = df![
+            "feature1" => [1000.0, 1000.1, 1000.2, 1000.3, 1000.4]
+        ]
+        .unwrap();
+
+        let mut z_score_scaler =
+            ZScoreScaler::new().with_near_zero_thresholds(NearZeroThresholds {
+                absolute: 1e-8,
+                relative: Some(1e-3),
+            });
+        let result = z_score_scaler.standardize(&valid_df, 4);
+        assert!(matches!(
+            result,
+            Err(ScalingError::NearZeroDenominatorError(..))
+        ));
+
+        let mut z_score_scaler = ZScoreScaler::new();
+        assert!(z_score_scaler.standardize(&valid_df, 4).is_ok());
+    }
+
+    #[test]
+    fn test_standardization_method_tiny_scale() {
+        let valid_df = df![
+            "feature1" => [1e-10, 2e-10, 3e-10, 4e-10, 5e-10]
+        ]
+        .unwrap();
+
+        let mut z_score_scaler = ZScoreScaler::new();
+        assert!(matches!(
+            z_score_scaler.standardize(&valid_df, 2),
+            Err(ScalingError::NearZeroDenominatorError(..))
+        ));
+
+        let mut z_score_scaler =
+            ZScoreScaler::new().with_near_zero_thresholds(NearZeroThresholds {
+                absolute: 0.0,
+                relative: Some(1e-3),
+            });
+        let standardized_df = z_score_scaler.standardize(&valid_df, 2).unwrap();
+        assert_eq!(
+            standardized_df,
+            df!["feature1" => [-1.26, -0.63, 0.0, 0.63, 1.26]].unwrap()
+        );
+
+        let restored_df = z_score_scaler
+            .inverse_transform(&z_score_scaler.transform(&valid_df).unwrap())
+            .unwrap();
+        let restored = restored_df.column("feature1").unwrap().f64().unwrap();
+        for (restored, original) in restored
+            .into_no_null_iter()
+            .zip([1e-10, 2e-10, 3e-10, 4e-10, 5e-10])
+        {
+            assert_abs_diff_eq!(restored, original, epsilon = 1e-20);
+        }
+    }
+
+    // Test fit, transform and inverse_transform methods
+    #[test]
+    fn test_transform_method() {
+        let valid_df = create_valid_df();
+        let mut z_score_scaler = ZScoreScaler::new();
+        z_score_scaler.fit(&valid_df).unwrap();
+
+        let standardized_df = z_score_scaler.transform(&valid_df).unwrap();
+        let rounded_df = standardized_df
+            .lazy()
+            .select([all().round(2)])
+            .collect()
+            .unwrap();
+        assert_eq!(rounded_df, create_standardized_df());
+    }
+
+    #[test]
+    fn test_inverse_transform_method() {
+        let valid_df = create_valid_df();
+        let mut z_score_scaler = ZScoreScaler::new();
+        z_score_scaler.fit(&valid_df).unwrap();
+
+        let standardized_df = z_score_scaler.transform(&valid_df).unwrap();
+        let restored_df = z_score_scaler
+            .inverse_transform(&standardized_df)
+            .unwrap()
+            .lazy()
+            .select([all().round(8)])
+            .collect()
+            .unwrap();
+        assert_eq!(restored_df, valid_df);
+    }
+
+    #[test]
+    fn test_transform_invalid() {
+        let z_score_scaler = ZScoreScaler::new();
+        assert!(matches!(
+            z_score_scaler.transform(&create_valid_df()),
+            Err(ScalingError::NotFittedError)
+        ));
+
+        let mut z_score_scaler = ZScoreScaler::new();
+        z_score_scaler.fit(&create_valid_df()).unwrap();
+        let df = df!["feature1" => [1.0]].unwrap();
+        assert!(matches!(
+            z_score_scaler.inverse_transform(&df),
+            Err(ScalingError::ValidationError(ValidationError::MissingColumnsError(ref cols)))
+                if cols == "feature2"
+        ));
+    }
+
     // Test reset method
     #[test]
     fn test_reset_method() {
@@ -3,7 +3,9 @@ use polars::prelude::DataFrame;
 use super::standardization::error::ScalingError;
 
 pub trait FeatureScaler {
-    /// Computes scaling parameters and returns the transformed dataframe
+    /// Computes scaling parameters and returns the transformed dataframe rounded to `decimals`.
+    ///
+    /// The stored parameters are kept unrounded, so later transforms stay exact.
     fn standardize(&mut self, df: &DataFrame, decimals: u32) -> Result<DataFrame, ScalingError>;
 
     /// Reset internal state
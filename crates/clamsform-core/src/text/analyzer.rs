@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// How a document is split into tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tokenizer {
+    /// Runs of at least two alphanumeric or `_` characters; punctuation separates tokens.
+    Word,
+    /// Runs of non-whitespace characters, keeping punctuation.
+    Whitespace,
+    /// Single characters, with runs of whitespace collapsed to one space. Combined with
+    /// n-grams, this yields character n-grams.
+    Character,
+}
+
+/// Turns documents into the terms counted by the vectorizers: tokens and their n-grams.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextAnalyzer {
+    pub tokenizer: Tokenizer,
+    pub lowercase: bool,
+    /// The inclusive range of n-gram lengths, such as `(1, 2)` for unigrams and bigrams.
+    pub ngram_range: (usize, usize),
+    /// Tokens removed before building the n-grams.
+    pub stop_words: Vec<String>,
+}
+
+impl Default for TextAnalyzer {
+    fn default() -> Self {
+        TextAnalyzer {
+            tokenizer: Tokenizer::Word,
+            lowercase: true,
+            ngram_range: (1, 1),
+            stop_words: Vec::new(),
+        }
+    }
+}
+
+impl TextAnalyzer {
+    /// Splits a document into tokens.
+    pub fn tokenize(&self, document: &str) -> Vec<String> {
+        let document = if self.lowercase {
+            document.to_lowercase()
+        } else {
+            document.to_string()
+        };
+
+        let tokens: Vec<String> = match self.tokenizer {
+            Tokenizer::Word => document
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .filter(|token| token.chars().nth(1).is_some())
+                .map(|token| token.to_string())
+                .collect(),
+            Tokenizer::Whitespace => document
+                .split_whitespace()
+                .map(|token| token.to_string())
+                .collect(),
+            Tokenizer::Character => document
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .chars()
+                .map(|c| c.to_string())
+                .collect(),
+        };
+
+        if self.stop_words.is_empty() {
+            return tokens;
+        }
+        let stop_words: HashSet<&str> = self.stop_words.iter().map(|word| word.as_str()).collect();
+        tokens
+            .into_iter()
+            .filter(|token| !stop_words.contains(token.as_str()))
+            .collect()
+    }
+
+    /// Splits a document into its terms: the n-grams of its tokens, shortest first.
+    pub fn analyze(&self, document: &str) -> Vec<String> {
+        let tokens = self.tokenize(document);
+        let separator = match self.tokenizer {
+            Tokenizer::Character => "",
+            Tokenizer::Word | Tokenizer::Whitespace => " ",
+        };
+
+        let (min_n, max_n) = self.ngram_range;
+        (min_n.max(1)..=max_n)
+            .flat_map(|n| tokens.windows(n).map(|window| window.join(separator)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test constructor and default
+    #[test]
+    fn test_default() {
+        let analyzer = TextAnalyzer::default();
+        assert_eq!(analyzer.tokenizer, Tokenizer::Word);
+        assert!(analyzer.lowercase);
+        assert_eq!(analyzer.ngram_range, (1, 1));
+        assert!(analyzer.stop_words.is_empty());
+    }
+
+    // Test tokenize method
+    #[test]
+    fn test_tokenize() {
+        let document = "The quick-brown fox, a FOX!";
+
+        let analyzer = TextAnalyzer::default();
+        assert_eq!(
+            analyzer.tokenize(document),
+            ["the", "quick", "brown", "fox", "fox"]
+        );
+
+        let analyzer = TextAnalyzer {
+            tokenizer: Tokenizer::Whitespace,
+            lowercase: false,
+            ..TextAnalyzer::default()
+        };
+        assert_eq!(
+            analyzer.tokenize(document),
+            ["The", "quick-brown", "fox,", "a", "FOX!"]
+        );
+
+        let analyzer = TextAnalyzer {
+            tokenizer: Tokenizer::Character,
+            ..TextAnalyzer::default()
+        };
+        assert_eq!(analyzer.tokenize("a  B"), ["a", " ", "b"]);
+    }
+
+    #[test]
+    fn test_tokenize_stop_words() {
+        let analyzer = TextAnalyzer {
+            stop_words: vec!["the".to_string(), "a".to_string()],
+            ..TextAnalyzer::default()
+        };
+        assert_eq!(
+            analyzer.tokenize("the cat and the hat"),
+            ["cat", "and", "hat"]
+        );
+    }
+
+    // Test analyze method
+    #[test]
+    fn test_analyze_ngrams() {
+        let analyzer = TextAnalyzer {
+            ngram_range: (1, 2),
+            ..TextAnalyzer::default()
+        };
+        assert_eq!(
+            analyzer.analyze("red fox jumps"),
+            ["red", "fox", "jumps", "red fox", "fox jumps"]
+        );
+
+        let analyzer = TextAnalyzer {
+            tokenizer: Tokenizer::Character,
+            ngram_range: (2, 2),
+            ..TextAnalyzer::default()
+        };
+        assert_eq!(analyzer.analyze("abc"), ["ab", "bc"]);
+    }
+}
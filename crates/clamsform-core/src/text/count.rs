@@ -0,0 +1,621 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use polars::prelude::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::analyzer::*;
+use super::error::*;
+use crate::validation::error::*;
+
+/// A document frequency limit, as a number of documents or as a share of the documents.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DocumentFrequency {
+    Count(usize),
+    /// A share of the documents, between 0 and 1.
+    Proportion(f64),
+}
+
+impl DocumentFrequency {
+    fn n_documents(&self, n_documents: usize) -> f64 {
+        match self {
+            DocumentFrequency::Count(count) => *count as f64,
+            DocumentFrequency::Proportion(proportion) => proportion * n_documents as f64,
+        }
+    }
+}
+
+/// The terms learned for a single text column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Vocabulary {
+    pub column: String,
+    /// The kept terms, in lexical order, each receiving its own column.
+    pub terms: Vec<String>,
+    /// The number of fitted documents containing each term.
+    pub document_frequencies: Vec<u32>,
+    /// The number of fitted documents.
+    pub n_documents: u32,
+}
+
+/// The learned vocabulary of every column together with the analyzer that produced its
+/// terms, as serialized by `vocabulary_to_json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedVocabulary {
+    pub analyzer: TextAnalyzer,
+    pub vocabulary: Vec<Vocabulary>,
+}
+
+impl SavedVocabulary {
+    /// Validates that every vocabulary is consistent, as a saved vocabulary may have been
+    /// edited or truncated.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every term has a document frequency between 1 and the number of
+    ///   documents.
+    /// * `Err(TextError::InvalidParameterError)` naming the first inconsistent column.
+    pub fn validate(&self) -> Result<(), TextError> {
+        for vocabulary in &self.vocabulary {
+            let column = &vocabulary.column;
+            if vocabulary.terms.len() != vocabulary.document_frequencies.len() {
+                return Err(TextError::InvalidParameterError(format!(
+                    "the vocabulary of {column} has {} terms but {} document frequencies",
+                    vocabulary.terms.len(),
+                    vocabulary.document_frequencies.len()
+                )));
+            }
+
+            let n_documents = vocabulary.n_documents;
+            if vocabulary
+                .document_frequencies
+                .iter()
+                .any(|&frequency| frequency == 0 || frequency > n_documents)
+            {
+                return Err(TextError::InvalidParameterError(format!(
+                    "the document frequencies of {column} must lie between 1 and the \
+                    {n_documents} fitted documents"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The term counts of a document, as `(term index, count)` pairs in term order.
+pub(crate) type DocumentCounts = Vec<(usize, u32)>;
+
+/// Replaces every text column with the number of times each term of its vocabulary occurs
+/// in each document. Count columns are UInt32 and named `<col>_<term>`.
+///
+/// Missing documents are treated as empty documents.
+pub struct CountVectorizer {
+    analyzer: TextAnalyzer,
+    min_df: DocumentFrequency,
+    max_df: DocumentFrequency,
+    max_features: Option<usize>,
+    vocabulary: Option<Vec<Vocabulary>>,
+}
+
+impl Default for CountVectorizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CountVectorizer {
+    pub fn new() -> Self {
+        CountVectorizer {
+            analyzer: TextAnalyzer::default(),
+            min_df: DocumentFrequency::Count(1),
+            max_df: DocumentFrequency::Proportion(1.0),
+            max_features: None,
+            vocabulary: None,
+        }
+    }
+
+    pub fn with_analyzer(mut self, analyzer: TextAnalyzer) -> Self {
+        self.analyzer = analyzer;
+        self
+    }
+
+    pub fn with_tokenizer(mut self, tokenizer: Tokenizer) -> Self {
+        self.analyzer.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.analyzer.lowercase = lowercase;
+        self
+    }
+
+    /// Sets the inclusive range of n-gram lengths. Defaults to unigrams only.
+    pub fn with_ngram_range(mut self, min_n: usize, max_n: usize) -> Self {
+        self.analyzer.ngram_range = (min_n, max_n);
+        self
+    }
+
+    pub fn with_stop_words(mut self, stop_words: Vec<String>) -> Self {
+        self.analyzer.stop_words = stop_words;
+        self
+    }
+
+    /// Drops the terms found in fewer documents. Defaults to 1 document.
+    pub fn with_min_df(mut self, min_df: DocumentFrequency) -> Self {
+        self.min_df = min_df;
+        self
+    }
+
+    /// Drops the terms found in more documents. Defaults to every document.
+    pub fn with_max_df(mut self, max_df: DocumentFrequency) -> Self {
+        self.max_df = max_df;
+        self
+    }
+
+    /// Keeps only the given number of terms of each column, the most frequent first.
+    pub fn with_max_features(mut self, max_features: usize) -> Self {
+        self.max_features = Some(max_features);
+        self
+    }
+
+    /// Uses the given vocabulary and analyzer, such as those loaded with
+    /// `vocabulary_from_json`, instead of fitting the vectorizer.
+    ///
+    /// Fails with `TextError::InvalidParameterError` if the vocabulary is inconsistent.
+    pub fn with_vocabulary(mut self, saved: SavedVocabulary) -> Result<Self, TextError> {
+        saved.validate()?;
+
+        self.analyzer = saved.analyzer;
+        self.vocabulary = Some(saved.vocabulary);
+        Ok(self)
+    }
+
+    pub fn analyzer(&self) -> &TextAnalyzer {
+        &self.analyzer
+    }
+
+    pub fn vocabulary(&self) -> Option<&[Vocabulary]> {
+        self.vocabulary.as_deref()
+    }
+
+    /// Serializes the learned vocabulary, with the analyzer that produced its terms, to a
+    /// JSON string.
+    pub fn vocabulary_to_json(&self) -> Result<String, TextError> {
+        let vocabulary = self.vocabulary.as_ref().ok_or(TextError::NotFittedError)?;
+        let saved = SavedVocabulary {
+            analyzer: self.analyzer.clone(),
+            vocabulary: vocabulary.clone(),
+        };
+        serde_json::to_string_pretty(&saved)
+            .map_err(|e| TextError::SerializationError(e.to_string()))
+    }
+
+    /// Parses a vocabulary and its analyzer from a JSON string.
+    pub fn vocabulary_from_json(json: &str) -> Result<SavedVocabulary, TextError> {
+        let saved: SavedVocabulary =
+            serde_json::from_str(json).map_err(|e| TextError::SerializationError(e.to_string()))?;
+        saved.validate()?;
+
+        Ok(saved)
+    }
+
+    fn validate_parameters(&self) -> Result<(), TextError> {
+        let (min_n, max_n) = self.analyzer.ngram_range;
+        if min_n == 0 || min_n > max_n {
+            return Err(TextError::InvalidParameterError(format!(
+                "the n-gram range must satisfy 1 <= min_n <= max_n, got ({min_n}, {max_n})"
+            )));
+        }
+
+        let invalid_proportions: Vec<String> = [self.min_df, self.max_df]
+            .iter()
+            .filter_map(|limit| match limit {
+                DocumentFrequency::Proportion(p) if !(0.0..=1.0).contains(p) => Some(p.to_string()),
+                _ => None,
+            })
+            .collect();
+        if !invalid_proportions.is_empty() {
+            return Err(TextError::InvalidParameterError(format!(
+                "document frequency proportions must be between 0 and 1, got {}",
+                invalid_proportions.join(", ")
+            )));
+        }
+
+        if self.max_features == Some(0) {
+            return Err(TextError::InvalidParameterError(
+                "max_features must be at least 1".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn fit_column(&self, column: &Column) -> Result<Vocabulary, TextError> {
+        let n_documents = column.len();
+        let mut frequencies: HashMap<String, (u32, u64)> = HashMap::new();
+
+        for document in column.str()? {
+            let mut seen: HashSet<String> = HashSet::new();
+            for term in self.analyzer.analyze(document.unwrap_or_default()) {
+                let (document_frequency, total) = frequencies.entry(term.clone()).or_default();
+                *total += 1;
+                if seen.insert(term) {
+                    *document_frequency += 1;
+                }
+            }
+        }
+
+        let min_df = self.min_df.n_documents(n_documents);
+        let max_df = self.max_df.n_documents(n_documents);
+        if min_df > max_df {
+            return Err(TextError::InvalidParameterError(format!(
+                "min_df ({min_df} documents) is greater than max_df ({max_df} documents)"
+            )));
+        }
+
+        let mut kept: Vec<(String, u32, u64)> = frequencies
+            .into_iter()
+            .filter(|(_, (document_frequency, _))| {
+                (min_df..=max_df).contains(&(*document_frequency as f64))
+            })
+            .map(|(term, (document_frequency, total))| (term, document_frequency, total))
+            .collect();
+
+        if let Some(max_features) = self.max_features {
+            kept.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+            kept.truncate(max_features);
+        }
+        kept.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(Vocabulary {
+            column: column.name().to_string(),
+            terms: kept.iter().map(|(term, ..)| term.clone()).collect(),
+            document_frequencies: kept.iter().map(|(_, frequency, _)| *frequency).collect(),
+            n_documents: n_documents as u32,
+        })
+    }
+
+    /// Learns the vocabulary of every text column.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TextError> {
+        self.validate_parameters()?;
+        validate_not_empty_df(df)?;
+        validate_text_columns(df)?;
+
+        let vocabulary = df
+            .get_columns()
+            .iter()
+            .map(|column| self.fit_column(column))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let empty_cols: Vec<&str> = vocabulary
+            .iter()
+            .filter(|fitted| fitted.terms.is_empty())
+            .map(|fitted| fitted.column.as_str())
+            .collect();
+        if !empty_cols.is_empty() {
+            return Err(TextError::EmptyVocabularyError(empty_cols.join(", ")));
+        }
+
+        self.vocabulary = Some(vocabulary);
+
+        Ok(())
+    }
+
+    /// Counts the terms of the vocabulary in every document of a column.
+    fn document_counts(
+        &self,
+        column: &Column,
+        vocabulary: &Vocabulary,
+    ) -> Result<Vec<DocumentCounts>, TextError> {
+        let index: HashMap<&str, usize> = vocabulary
+            .terms
+            .iter()
+            .enumerate()
+            .map(|(i, term)| (term.as_str(), i))
+            .collect();
+
+        let counts = column
+            .str()?
+            .into_iter()
+            .map(|document| {
+                let mut counts: HashMap<usize, u32> = HashMap::new();
+                for term in self.analyzer.analyze(document.unwrap_or_default()) {
+                    if let Some(&i) = index.get(term.as_str()) {
+                        *counts.entry(i).or_default() += 1;
+                    }
+                }
+                let mut counts: DocumentCounts = counts.into_iter().collect();
+                counts.sort_unstable();
+                counts
+            })
+            .collect();
+
+        Ok(counts)
+    }
+
+    /// Replaces every fitted column with the columns built by `vectorize` from its term
+    /// counts. Other columns are passed through.
+    pub(crate) fn vectorize(
+        &self,
+        df: &DataFrame,
+        vectorize: impl Fn(&Vocabulary, &[DocumentCounts]) -> Vec<Column>,
+    ) -> Result<DataFrame, TextError> {
+        let vocabulary = self.vocabulary.as_ref().ok_or(TextError::NotFittedError)?;
+
+        let fitted: Vec<&str> = vocabulary
+            .iter()
+            .map(|fitted| fitted.column.as_str())
+            .collect();
+        validate_columns_present(df, &fitted)?;
+        validate_text_columns(&df.select(fitted)?)?;
+
+        let mut columns: Vec<Column> = Vec::new();
+        for column in df.get_columns() {
+            match vocabulary
+                .iter()
+                .find(|fitted| fitted.column == column.name().as_str())
+            {
+                Some(fitted) => {
+                    let counts = self.document_counts(column, fitted)?;
+                    columns.extend(vectorize(fitted, &counts));
+                },
+                None => columns.push(column.clone()),
+            }
+        }
+
+        Ok(DataFrame::new(columns)?)
+    }
+
+    /// Replaces every fitted column with its term count columns.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TextError> {
+        self.vectorize(df, |vocabulary, counts| {
+            let mut values = vec![vec![0u32; counts.len()]; vocabulary.terms.len()];
+            for (row, document) in counts.iter().enumerate() {
+                for &(term, count) in document {
+                    values[term][row] = count;
+                }
+            }
+
+            term_columns(vocabulary, values)
+        })
+    }
+
+    /// Learns the vocabulary and returns the vectorized DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TextError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.vocabulary = None;
+    }
+}
+
+/// Names the value columns of every term of a vocabulary `<col>_<term>`.
+pub(crate) fn term_columns<T>(vocabulary: &Vocabulary, values: Vec<Vec<T>>) -> Vec<Column>
+where
+    Series: NamedFrom<Vec<T>, [T]>,
+{
+    vocabulary
+        .terms
+        .iter()
+        .zip(values)
+        .map(|(term, values)| {
+            Series::new(format!("{}_{term}", vocabulary.column).into(), values).into()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "text" => [Some("the red fox"), Some("the red hen, the end"), None],
+            "id" => [1i32, 2, 3]
+        ]
+        .unwrap()
+    }
+
+    fn terms(vectorizer: &CountVectorizer) -> &[String] {
+        &vectorizer.vocabulary().unwrap()[0].terms
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let vectorizer = CountVectorizer::new();
+        assert_eq!(vectorizer.analyzer(), &TextAnalyzer::default());
+        assert_eq!(vectorizer.min_df, DocumentFrequency::Count(1));
+        assert_eq!(vectorizer.max_df, DocumentFrequency::Proportion(1.0));
+        assert!(vectorizer.max_features.is_none());
+        assert!(vectorizer.vocabulary().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut vectorizer = CountVectorizer::new();
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            vectorizer.vocabulary().unwrap(),
+            [Vocabulary {
+                column: "text".to_string(),
+                terms: vec![
+                    "end".to_string(),
+                    "fox".to_string(),
+                    "hen".to_string(),
+                    "red".to_string(),
+                    "the".to_string()
+                ],
+                document_frequencies: vec![1, 1, 1, 2, 2],
+                n_documents: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_fit_document_frequency_limits() {
+        let df = create_df().select(["text"]).unwrap();
+
+        let mut vectorizer = CountVectorizer::new().with_min_df(DocumentFrequency::Count(2));
+        vectorizer.fit(&df).unwrap();
+        assert_eq!(terms(&vectorizer), ["red", "the"]);
+
+        let mut vectorizer = CountVectorizer::new().with_max_df(DocumentFrequency::Proportion(0.5));
+        vectorizer.fit(&df).unwrap();
+        assert_eq!(terms(&vectorizer), ["end", "fox", "hen"]);
+    }
+
+    #[test]
+    fn test_fit_max_features() {
+        // "the" occurs three times, "red" twice, and the ties are broken lexically.
+        let mut vectorizer = CountVectorizer::new().with_max_features(3);
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+        assert_eq!(terms(&vectorizer), ["end", "red", "the"]);
+    }
+
+    #[test]
+    fn test_fit_ngrams_and_stop_words() {
+        let mut vectorizer = CountVectorizer::new()
+            .with_ngram_range(2, 2)
+            .with_stop_words(vec!["the".to_string()]);
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+        assert_eq!(terms(&vectorizer), ["hen end", "red fox", "red hen"]);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut vectorizer = CountVectorizer::new();
+        assert!(matches!(
+            vectorizer.fit(&create_df()),
+            Err(TextError::NonTextError(ref cols)) if cols == "id"
+        ));
+
+        let mut vectorizer = CountVectorizer::new().with_ngram_range(2, 1);
+        assert!(matches!(
+            vectorizer.fit(&create_df().select(["text"]).unwrap()),
+            Err(TextError::InvalidParameterError(_))
+        ));
+
+        let mut vectorizer = CountVectorizer::new().with_stop_words(vec!["a".to_string()]);
+        let df = df!["text" => ["a", "a a"]].unwrap();
+        assert!(matches!(
+            vectorizer.fit(&df),
+            Err(TextError::EmptyVocabularyError(ref cols)) if cols == "text"
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut vectorizer = CountVectorizer::new().with_min_df(DocumentFrequency::Count(2));
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+        let vectorized_df = vectorizer.transform(&create_df()).unwrap();
+
+        let expected = df![
+            "text_red" => [1u32, 1, 0],
+            "text_the" => [1u32, 2, 0],
+            "id" => [1i32, 2, 3]
+        ]
+        .unwrap();
+        assert_eq!(vectorized_df, expected);
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let vectorizer = CountVectorizer::new();
+        assert!(matches!(
+            vectorizer.transform(&create_df()),
+            Err(TextError::NotFittedError)
+        ));
+    }
+
+    // Test vocabulary serialization
+    #[test]
+    fn test_vocabulary_json_round_trip() {
+        let mut vectorizer = CountVectorizer::new()
+            .with_lowercase(false)
+            .with_ngram_range(1, 2);
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+
+        let json = vectorizer.vocabulary_to_json().unwrap();
+        let saved = CountVectorizer::vocabulary_from_json(&json).unwrap();
+        assert_eq!(saved.vocabulary, vectorizer.vocabulary().unwrap());
+        assert_eq!(&saved.analyzer, vectorizer.analyzer());
+
+        let loaded = CountVectorizer::new().with_vocabulary(saved).unwrap();
+        assert_eq!(loaded.analyzer(), vectorizer.analyzer());
+        assert_eq!(
+            loaded.transform(&create_df()).unwrap(),
+            vectorizer.transform(&create_df()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_vocabulary_json_invalid() {
+        let mut vectorizer = CountVectorizer::new();
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+        let json = vectorizer.vocabulary_to_json().unwrap();
+
+        let mut saved = CountVectorizer::vocabulary_from_json(&json).unwrap();
+        saved.vocabulary[0].document_frequencies.pop();
+        assert!(matches!(
+            CountVectorizer::new().with_vocabulary(saved),
+            Err(TextError::InvalidParameterError(_))
+        ));
+
+        let mut saved = CountVectorizer::vocabulary_from_json(&json).unwrap();
+        saved.vocabulary[0].document_frequencies[0] = 0;
+        let json = serde_json::to_string(&saved).unwrap();
+        assert!(matches!(
+            CountVectorizer::vocabulary_from_json(&json),
+            Err(TextError::InvalidParameterError(_))
+        ));
+
+        let mut saved =
+            CountVectorizer::vocabulary_from_json(&vectorizer.vocabulary_to_json().unwrap())
+                .unwrap();
+        saved.vocabulary[0].n_documents = 0;
+        assert!(matches!(
+            CountVectorizer::new().with_vocabulary(saved),
+            Err(TextError::InvalidParameterError(_))
+        ));
+
+        assert!(matches!(
+            CountVectorizer::vocabulary_from_json("{"),
+            Err(TextError::SerializationError(_))
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut vectorizer = CountVectorizer::new();
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+
+        vectorizer.reset();
+        assert!(vectorizer.vocabulary().is_none());
+    }
+}
@@ -0,0 +1,82 @@
+use polars::prelude::*;
+use thiserror::Error;
+
+use crate::validation::error::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum TextError {
+    #[error(
+        "The vectorizer has not been fitted. \
+        Call `fit` before attempting to transform a DataFrame."
+    )]
+    NotFittedError,
+
+    #[error(
+        "The column(s) {0} contain non-text data. \
+        Vectorizers can only be applied to string columns."
+    )]
+    NonTextError(String),
+
+    #[error(
+        "The column(s) {0} produced an empty vocabulary. \
+        The documents may only contain stop words, or the document frequency limits may be too strict."
+    )]
+    EmptyVocabularyError(String),
+
+    #[error("Invalid vectorizer parameter: {0}")]
+    InvalidParameterError(String),
+
+    #[error("The vocabulary could not be serialized: {0}")]
+    SerializationError(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
+
+/// Validates that all columns in the DataFrame hold text.
+///
+/// # Arguments
+/// * `df` - The DataFrame to validate column types.
+///
+/// # Returns
+/// * `Ok(())` if all columns are string columns.
+/// * `Err(TextError::NonTextError)` with a list of columns holding other data types.
+pub fn validate_text_columns(df: &DataFrame) -> Result<(), TextError> {
+    let non_text_cols: Vec<String> = df
+        .get_columns()
+        .iter()
+        .filter(|col| !col.dtype().is_string())
+        .map(|col| col.name().to_string())
+        .collect();
+
+    if !non_text_cols.is_empty() {
+        return Err(TextError::NonTextError(non_text_cols.join(", ")));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_text_columns() {
+        let invalid_df = df![
+            "text" => ["a b", "c"],
+            "float" => [1.0f64, 2.0]
+        ]
+        .unwrap();
+        let result = validate_text_columns(&invalid_df);
+        assert!(matches!(
+            result,
+            Err(TextError::NonTextError(ref cols)) if cols == "float"
+        ));
+
+        let valid_df = df!["text" => [Some("a b"), None]].unwrap();
+        assert!(validate_text_columns(&valid_df).is_ok());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod analyzer;
+pub mod count;
+pub mod error;
+pub mod tfidf;
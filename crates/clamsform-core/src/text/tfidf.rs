@@ -0,0 +1,341 @@
+use polars::prelude::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::count::*;
+use super::error::*;
+
+/// Replaces every text column with the TF-IDF weight of each term of its vocabulary in
+/// each document. Weight columns are Float64 and named `<col>_<term>`.
+///
+/// The idf of a term is `ln((1 + n) / (1 + df)) + 1` with smoothing and
+/// `ln(n / df) + 1` without, where `n` is the number of fitted documents and `df` the
+/// number of those containing the term. The vocabulary and its document frequencies are
+/// learned by the wrapped `CountVectorizer`, and `vocabulary_to_json` saves them together
+/// with the weighting options.
+pub struct TfidfVectorizer {
+    counts: CountVectorizer,
+    smooth_idf: bool,
+    sublinear_tf: bool,
+    normalize: bool,
+}
+
+/// The learned vocabulary of a TF-IDF vectorizer together with its analyzer and weighting
+/// options, as serialized by `TfidfVectorizer::vocabulary_to_json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedTfidfVocabulary {
+    pub smooth_idf: bool,
+    pub sublinear_tf: bool,
+    pub normalize: bool,
+    #[serde(flatten)]
+    pub counts: SavedVocabulary,
+}
+
+impl Default for TfidfVectorizer {
+    fn default() -> Self {
+        Self::new(CountVectorizer::new())
+    }
+}
+
+impl TfidfVectorizer {
+    /// Creates a vectorizer weighting the term counts of the given `CountVectorizer`.
+    pub fn new(counts: CountVectorizer) -> Self {
+        TfidfVectorizer {
+            counts,
+            smooth_idf: true,
+            sublinear_tf: false,
+            normalize: true,
+        }
+    }
+
+    /// Adds one to every document frequency, as if a document held every term once.
+    /// Defaults to true.
+    pub fn with_smooth_idf(mut self, smooth_idf: bool) -> Self {
+        self.smooth_idf = smooth_idf;
+        self
+    }
+
+    /// Replaces the term frequency `tf` with `1 + ln(tf)`. Defaults to false.
+    pub fn with_sublinear_tf(mut self, sublinear_tf: bool) -> Self {
+        self.sublinear_tf = sublinear_tf;
+        self
+    }
+
+    /// Scales the weights of every document and column to unit L2 norm. Defaults to true.
+    pub fn with_normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Uses the given vocabulary, analyzer and weighting options, such as those loaded with
+    /// `vocabulary_from_json`, instead of fitting the vectorizer.
+    pub fn with_vocabulary(self, saved: SavedTfidfVocabulary) -> Result<Self, TextError> {
+        Ok(TfidfVectorizer {
+            counts: self.counts.with_vocabulary(saved.counts)?,
+            smooth_idf: saved.smooth_idf,
+            sublinear_tf: saved.sublinear_tf,
+            normalize: saved.normalize,
+        })
+    }
+
+    pub fn count_vectorizer(&self) -> &CountVectorizer {
+        &self.counts
+    }
+
+    pub fn vocabulary(&self) -> Option<&[Vocabulary]> {
+        self.counts.vocabulary()
+    }
+
+    /// Serializes the learned vocabulary, with its analyzer and the weighting options, to a
+    /// JSON string.
+    pub fn vocabulary_to_json(&self) -> Result<String, TextError> {
+        let vocabulary = self.vocabulary().ok_or(TextError::NotFittedError)?;
+        let saved = SavedTfidfVocabulary {
+            smooth_idf: self.smooth_idf,
+            sublinear_tf: self.sublinear_tf,
+            normalize: self.normalize,
+            counts: SavedVocabulary {
+                analyzer: self.counts.analyzer().clone(),
+                vocabulary: vocabulary.to_vec(),
+            },
+        };
+        serde_json::to_string_pretty(&saved)
+            .map_err(|e| TextError::SerializationError(e.to_string()))
+    }
+
+    /// Parses a vocabulary, its analyzer and the weighting options from a JSON string.
+    pub fn vocabulary_from_json(json: &str) -> Result<SavedTfidfVocabulary, TextError> {
+        let saved: SavedTfidfVocabulary =
+            serde_json::from_str(json).map_err(|e| TextError::SerializationError(e.to_string()))?;
+        saved.counts.validate()?;
+
+        Ok(saved)
+    }
+
+    /// The idf of every term of a vocabulary.
+    pub fn idf(&self, vocabulary: &Vocabulary) -> Vec<f64> {
+        let n_documents = vocabulary.n_documents as f64;
+        vocabulary
+            .document_frequencies
+            .iter()
+            .map(|&document_frequency| {
+                let document_frequency = document_frequency as f64;
+                if self.smooth_idf {
+                    ((1.0 + n_documents) / (1.0 + document_frequency)).ln() + 1.0
+                } else {
+                    (n_documents / document_frequency).ln() + 1.0
+                }
+            })
+            .collect()
+    }
+
+    /// Learns the vocabulary and document frequencies of every text column.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TextError> {
+        self.counts.fit(df)
+    }
+
+    /// Replaces every fitted column with its TF-IDF weight columns.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TextError> {
+        self.counts.vectorize(df, |vocabulary, counts| {
+            let idf = self.idf(vocabulary);
+            let mut values = vec![vec![0.0f64; counts.len()]; vocabulary.terms.len()];
+
+            for (row, document) in counts.iter().enumerate() {
+                let weights: Vec<(usize, f64)> = document
+                    .iter()
+                    .map(|&(term, count)| {
+                        let tf = if self.sublinear_tf {
+                            1.0 + (count as f64).ln()
+                        } else {
+                            count as f64
+                        };
+                        (term, tf * idf[term])
+                    })
+                    .collect();
+
+                let norm = if self.normalize {
+                    weights
+                        .iter()
+                        .map(|(_, weight)| weight * weight)
+                        .sum::<f64>()
+                        .sqrt()
+                } else {
+                    1.0
+                };
+                for (term, weight) in weights {
+                    values[term][row] = if norm > 0.0 { weight / norm } else { weight };
+                }
+            }
+
+            term_columns(vocabulary, values)
+        })
+    }
+
+    /// Learns the vocabulary and returns the vectorized DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TextError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.counts.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "text" => [Some("red fox fox"), Some("red hen"), None],
+            "id" => [1i32, 2, 3]
+        ]
+        .unwrap()
+    }
+
+    fn weights(df: &DataFrame, column: &str) -> Vec<f64> {
+        df.column(column)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{actual:?} != {expected:?}");
+        }
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let vectorizer = TfidfVectorizer::default();
+        assert!(vectorizer.smooth_idf);
+        assert!(!vectorizer.sublinear_tf);
+        assert!(vectorizer.normalize);
+        assert!(vectorizer.vocabulary().is_none());
+    }
+
+    // Test idf method
+    #[test]
+    fn test_idf() {
+        let mut vectorizer = TfidfVectorizer::default();
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+        let vocabulary = vectorizer.vocabulary().unwrap()[0].clone();
+        assert_eq!(vocabulary.terms, ["fox", "hen", "red"]);
+
+        let smooth = (4.0f64 / 2.0).ln() + 1.0;
+        assert_close(
+            &vectorizer.idf(&vocabulary),
+            &[smooth, smooth, (4.0f64 / 3.0).ln() + 1.0],
+        );
+
+        let vectorizer = vectorizer.with_smooth_idf(false);
+        let unsmoothed = (3.0f64 / 1.0).ln() + 1.0;
+        assert_close(
+            &vectorizer.idf(&vocabulary),
+            &[unsmoothed, unsmoothed, (3.0f64 / 2.0).ln() + 1.0],
+        );
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_unnormalized() {
+        let mut vectorizer = TfidfVectorizer::default().with_normalize(false);
+        let vectorized_df = vectorizer
+            .fit_transform(&create_df().select(["text"]).unwrap())
+            .unwrap();
+
+        let rare = (4.0f64 / 2.0).ln() + 1.0;
+        let common = (4.0f64 / 3.0).ln() + 1.0;
+        assert_close(
+            &weights(&vectorized_df, "text_fox"),
+            &[2.0 * rare, 0.0, 0.0],
+        );
+        assert_close(&weights(&vectorized_df, "text_hen"), &[0.0, rare, 0.0]);
+        assert_close(&weights(&vectorized_df, "text_red"), &[common, common, 0.0]);
+
+        let vectorizer = vectorizer.with_sublinear_tf(true);
+        let vectorized_df = vectorizer.transform(&create_df()).unwrap();
+        assert_close(
+            &weights(&vectorized_df, "text_fox"),
+            &[(1.0 + 2.0f64.ln()) * rare, 0.0, 0.0],
+        );
+    }
+
+    #[test]
+    fn test_transform_normalized() {
+        let mut vectorizer = TfidfVectorizer::default();
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+        let vectorized_df = vectorizer.transform(&create_df()).unwrap();
+
+        assert_eq!(
+            vectorized_df.get_column_names(),
+            ["text_fox", "text_hen", "text_red", "id"]
+        );
+        for row in 0..3 {
+            let norm: f64 = ["text_fox", "text_hen", "text_red"]
+                .iter()
+                .map(|column| weights(&vectorized_df, column)[row].powi(2))
+                .sum::<f64>()
+                .sqrt();
+            // The missing document keeps all-zero weights.
+            let expected = if row == 2 { 0.0 } else { 1.0 };
+            assert!((norm - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let vectorizer = TfidfVectorizer::default();
+        assert!(matches!(
+            vectorizer.transform(&create_df()),
+            Err(TextError::NotFittedError)
+        ));
+    }
+
+    // Test vocabulary serialization
+    #[test]
+    fn test_vocabulary_json_round_trip() {
+        let mut vectorizer = TfidfVectorizer::default()
+            .with_smooth_idf(false)
+            .with_sublinear_tf(true)
+            .with_normalize(false);
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+
+        let json = vectorizer.vocabulary_to_json().unwrap();
+        let saved = TfidfVectorizer::vocabulary_from_json(&json).unwrap();
+        assert!(!saved.smooth_idf && saved.sublinear_tf && !saved.normalize);
+
+        let loaded = TfidfVectorizer::default().with_vocabulary(saved).unwrap();
+        assert_eq!(
+            loaded.transform(&create_df()).unwrap(),
+            vectorizer.transform(&create_df()).unwrap()
+        );
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut vectorizer = TfidfVectorizer::default();
+        vectorizer
+            .fit(&create_df().select(["text"]).unwrap())
+            .unwrap();
+
+        vectorizer.reset();
+        assert!(vectorizer.vocabulary().is_none());
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use polars::prelude::*;
+
+use super::error::*;
+use crate::validation::error::*;
+
+/// Builds the `sin` and `cos` of the angle of a value on a cycle of the given period.
+pub(crate) fn sin_cos_exprs(expr: Expr, period: Expr) -> (Expr, Expr) {
+    let angle = expr.cast(DataType::Float64) * lit(2.0 * PI) / period;
+    (angle.clone().sin(), angle.cos())
+}
+
+/// Encodes periodic numeric columns, such as hours or angles, as `<col>_sin` and `<col>_cos`
+/// columns, so that the ends of the cycle sit next to each other.
+///
+/// Columns without a given period learn it from their observed range: `max - min` for
+/// float columns, and `max - min + 1` for integer columns, whose values are steps of 1.
+pub struct CyclicalEncoder {
+    given_periods: HashMap<String, f64>,
+    periods: Option<Vec<(String, f64)>>,
+}
+
+impl Default for CyclicalEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CyclicalEncoder {
+    pub fn new() -> Self {
+        CyclicalEncoder {
+            given_periods: HashMap::new(),
+            periods: None,
+        }
+    }
+
+    /// Sets the period of `column`, such as 24 for hours or 360 for angles in degrees.
+    pub fn with_period(mut self, column: &str, period: f64) -> Self {
+        self.given_periods.insert(column.to_string(), period);
+        self
+    }
+
+    /// The period of every fitted column.
+    pub fn periods(&self) -> Option<&[(String, f64)]> {
+        self.periods.as_deref()
+    }
+
+    /// Learns the period of every column without a given period.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        validate_periodic_columns(df)?;
+
+        let period_exprs: Vec<Expr> = df
+            .get_columns()
+            .iter()
+            .map(|column| {
+                let name = column.name().as_str();
+                match self.given_periods.get(name) {
+                    Some(period) => lit(*period),
+                    None => {
+                        let range = col(name).max().cast(DataType::Float64)
+                            - col(name).min().cast(DataType::Float64);
+                        if column.dtype().is_integer() {
+                            range + lit(1.0)
+                        } else {
+                            range
+                        }
+                    },
+                }
+                .alias(name)
+            })
+            .collect();
+
+        let period_df = df.clone().lazy().select(period_exprs).collect()?;
+
+        let periods = period_df
+            .get_columns()
+            .iter()
+            .map(|period| {
+                let value = period.f64()?.get(0).unwrap_or(f64::NAN);
+                Ok((period.name().to_string(), value))
+            })
+            .collect::<Result<Vec<_>, TransformationError>>()?;
+
+        let invalid_cols: Vec<&str> = periods
+            .iter()
+            .filter(|(_, period)| !(period.is_finite() && *period > 0.0))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if !invalid_cols.is_empty() {
+            return Err(TransformationError::InvalidParameterError(format!(
+                "the period of column(s) {} must be positive and finite",
+                invalid_cols.join(", ")
+            )));
+        }
+
+        self.periods = Some(periods);
+
+        Ok(())
+    }
+
+    /// Replaces the fitted columns of a lazy frame with their `<col>_sin` and `<col>_cos`
+    /// columns, appended after the other columns.
+    pub fn transform_lazy(&self, lf: LazyFrame) -> Result<LazyFrame, TransformationError> {
+        let periods = self
+            .periods
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let mut exprs: Vec<Expr> = Vec::with_capacity(2 * periods.len());
+        for (name, period) in periods {
+            let (sin, cos) = sin_cos_exprs(col(name.as_str()), lit(*period));
+            exprs.push(sin.alias(format!("{name}_sin")));
+            exprs.push(cos.alias(format!("{name}_cos")));
+        }
+        let names: Vec<&str> = periods.iter().map(|(name, _)| name.as_str()).collect();
+
+        Ok(lf.with_columns(exprs).drop(names))
+    }
+
+    /// Replaces the fitted columns with their `<col>_sin` and `<col>_cos` columns, appended
+    /// after the other columns.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        let periods = self
+            .periods
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let fitted: Vec<&str> = periods.iter().map(|(name, _)| name.as_str()).collect();
+        validate_columns_present(df, &fitted)?;
+        validate_periodic_columns(&df.select(fitted)?)?;
+
+        Ok(self.transform_lazy(df.clone().lazy())?.collect()?)
+    }
+
+    /// Learns the periods and returns the encoded DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.periods = None;
+    }
+}
+
+/// Validates that every column is an integer or float column with valid values.
+fn validate_periodic_columns(df: &DataFrame) -> Result<(), TransformationError> {
+    let non_numeric_cols: Vec<String> = df
+        .get_columns()
+        .iter()
+        .filter(|col| !col.dtype().is_primitive_numeric())
+        .map(|col| col.name().to_string())
+        .collect();
+    if !non_numeric_cols.is_empty() {
+        Err(ValidationError::NonNumericError(
+            non_numeric_cols.join(", "),
+        ))?;
+    }
+
+    let float_df = df
+        .clone()
+        .lazy()
+        .select([all().cast(DataType::Float64)])
+        .collect()?;
+    validate_dataframe(&float_df)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "hour" => [0i32, 6, 12, 23],
+            "angle" => [0.0f64, 90.0, 180.0, 360.0],
+            "label" => ["a", "b", "c", "d"]
+        ]
+        .unwrap()
+    }
+
+    fn values(df: &DataFrame, name: &str) -> Vec<f64> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let encoder = CyclicalEncoder::new();
+        assert!(encoder.given_periods.is_empty());
+        assert!(encoder.periods().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_learned_periods() {
+        let mut encoder = CyclicalEncoder::new();
+        encoder
+            .fit(&create_df().select(["hour", "angle"]).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            encoder.periods().unwrap(),
+            [("hour".to_string(), 24.0), ("angle".to_string(), 360.0)]
+        );
+    }
+
+    #[test]
+    fn test_fit_given_period() {
+        let mut encoder = CyclicalEncoder::new().with_period("angle", 720.0);
+        encoder
+            .fit(&create_df().select(["angle"]).unwrap())
+            .unwrap();
+
+        assert_eq!(encoder.periods().unwrap(), [("angle".to_string(), 720.0)]);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut encoder = CyclicalEncoder::new();
+        assert!(matches!(
+            encoder.fit(&create_df()),
+            Err(TransformationError::ValidationError(
+                ValidationError::NonNumericError(ref cols)
+            )) if cols == "label"
+        ));
+
+        let df = df!["constant" => [1.0f64, 1.0]].unwrap();
+        assert!(matches!(
+            encoder.fit(&df),
+            Err(TransformationError::InvalidParameterError(_))
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut encoder = CyclicalEncoder::new().with_period("hour", 24.0);
+        encoder.fit(&create_df().select(["hour"]).unwrap()).unwrap();
+        let encoded_df = encoder.transform(&create_df()).unwrap();
+
+        assert_eq!(
+            encoded_df.get_column_names(),
+            ["angle", "label", "hour_sin", "hour_cos"]
+        );
+
+        let sin = values(&encoded_df, "hour_sin");
+        let cos = values(&encoded_df, "hour_cos");
+        assert_abs_diff_eq!(sin[0], 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(cos[0], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(sin[1], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(cos[2], -1.0, epsilon = 1e-12);
+
+        // 23:00 sits next to 00:00.
+        let gap = ((sin[3] - sin[0]).powi(2) + (cos[3] - cos[0]).powi(2)).sqrt();
+        assert_abs_diff_eq!(gap, 2.0 * (PI / 24.0).sin(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transform_lazy() {
+        let mut encoder = CyclicalEncoder::new();
+        encoder
+            .fit(&create_df().select(["angle"]).unwrap())
+            .unwrap();
+
+        let lazy_df = encoder
+            .transform_lazy(create_df().lazy())
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(lazy_df, encoder.transform(&create_df()).unwrap());
+
+        // The learned period is 360, so 0 and 360 degrees coincide.
+        let cos = values(&lazy_df, "angle_cos");
+        assert_abs_diff_eq!(cos[0], cos[3], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let encoder = CyclicalEncoder::new();
+        assert!(matches!(
+            encoder.transform(&create_df()),
+            Err(TransformationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut encoder = CyclicalEncoder::new();
+        encoder.fit(&create_df().select(["hour"]).unwrap()).unwrap();
+
+        encoder.reset();
+        assert!(encoder.periods().is_none());
+    }
+}
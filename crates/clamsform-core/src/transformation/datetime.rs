@@ -0,0 +1,435 @@
+use polars::prelude::*;
+
+use super::cyclical::sin_cos_exprs;
+use super::error::*;
+use crate::validation::error::*;
+
+/// A calendar or clock component extracted from a `Date` or `Datetime` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatetimeComponent {
+    Year,
+    Quarter,
+    Month,
+    /// ISO week of the year, from 1 to 53.
+    WeekOfYear,
+    /// Day of the month, from 1 to 31.
+    Day,
+    /// ISO day of the week, from 1 (Monday) to 7 (Sunday).
+    DayOfWeek,
+    /// Day of the year, from 1 to 366.
+    DayOfYear,
+    Hour,
+    Minute,
+    Second,
+    /// 1 on Saturdays and Sundays, 0 otherwise.
+    IsWeekend,
+}
+
+impl DatetimeComponent {
+    /// The suffix of the output column.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            DatetimeComponent::Year => "year",
+            DatetimeComponent::Quarter => "quarter",
+            DatetimeComponent::Month => "month",
+            DatetimeComponent::WeekOfYear => "week_of_year",
+            DatetimeComponent::Day => "day",
+            DatetimeComponent::DayOfWeek => "day_of_week",
+            DatetimeComponent::DayOfYear => "day_of_year",
+            DatetimeComponent::Hour => "hour",
+            DatetimeComponent::Minute => "minute",
+            DatetimeComponent::Second => "second",
+            DatetimeComponent::IsWeekend => "is_weekend",
+        }
+    }
+
+    /// The period of the component, or `None` if it is not periodic.
+    pub fn period(&self) -> Option<f64> {
+        match self {
+            DatetimeComponent::Year | DatetimeComponent::IsWeekend => None,
+            DatetimeComponent::Quarter => Some(4.0),
+            DatetimeComponent::Month => Some(12.0),
+            DatetimeComponent::WeekOfYear => Some(53.0),
+            DatetimeComponent::Day => Some(31.0),
+            DatetimeComponent::DayOfWeek => Some(7.0),
+            DatetimeComponent::DayOfYear => Some(366.0),
+            DatetimeComponent::Hour => Some(24.0),
+            DatetimeComponent::Minute | DatetimeComponent::Second => Some(60.0),
+        }
+    }
+
+    fn is_time_of_day(&self) -> bool {
+        matches!(
+            self,
+            DatetimeComponent::Hour | DatetimeComponent::Minute | DatetimeComponent::Second
+        )
+    }
+
+    fn expr(&self, expr: Expr) -> Expr {
+        let dt = expr.dt();
+        match self {
+            DatetimeComponent::Year => dt.year(),
+            DatetimeComponent::Quarter => dt.quarter(),
+            DatetimeComponent::Month => dt.month(),
+            DatetimeComponent::WeekOfYear => dt.week(),
+            DatetimeComponent::Day => dt.day(),
+            DatetimeComponent::DayOfWeek => dt.weekday(),
+            DatetimeComponent::DayOfYear => dt.ordinal_day(),
+            DatetimeComponent::Hour => dt.hour(),
+            DatetimeComponent::Minute => dt.minute(),
+            DatetimeComponent::Second => dt.second(),
+            DatetimeComponent::IsWeekend => {
+                return dt.weekday().gt_eq(lit(6)).cast(DataType::UInt8);
+            },
+        }
+        .cast(DataType::Int32)
+    }
+}
+
+/// Expands `Date` and `Datetime` columns into calendar and clock components named
+/// `<col>_<component>`, such as `ts_month` or `ts_day_of_week`.
+///
+/// Clock components are skipped for `Date` columns. With cyclical encodings, every
+/// periodic component also gets `<col>_<component>_sin` and `<col>_<component>_cos` columns.
+pub struct DatetimeFeatures {
+    components: Vec<DatetimeComponent>,
+    cyclical: bool,
+    time_zone: Option<String>,
+    keep_original: bool,
+    datetime_columns: Option<Vec<String>>,
+}
+
+impl Default for DatetimeFeatures {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatetimeFeatures {
+    pub fn new() -> Self {
+        DatetimeFeatures {
+            components: vec![
+                DatetimeComponent::Year,
+                DatetimeComponent::Month,
+                DatetimeComponent::Day,
+                DatetimeComponent::DayOfWeek,
+                DatetimeComponent::DayOfYear,
+                DatetimeComponent::Hour,
+                DatetimeComponent::IsWeekend,
+            ],
+            cyclical: false,
+            time_zone: None,
+            keep_original: false,
+            datetime_columns: None,
+        }
+    }
+
+    pub fn with_components(mut self, components: Vec<DatetimeComponent>) -> Self {
+        self.components = components;
+        self
+    }
+
+    /// Adds sin/cos encodings of the periodic components.
+    pub fn with_cyclical(mut self, cyclical: bool) -> Self {
+        self.cyclical = cyclical;
+        self
+    }
+
+    /// Extracts the components in the given time zone, such as `Europe/Paris`.
+    ///
+    /// Datetimes without a time zone are assumed to be in UTC.
+    pub fn with_time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// Keeps the original columns ahead of their components, instead of dropping them.
+    pub fn with_keep_original(mut self, keep_original: bool) -> Self {
+        self.keep_original = keep_original;
+        self
+    }
+
+    /// The `Date` and `Datetime` columns seen at fit time.
+    pub fn datetime_columns(&self) -> Option<&[String]> {
+        self.datetime_columns.as_deref()
+    }
+
+    fn source_expr(&self, name: &str, dtype: &DataType) -> Expr {
+        match (dtype, &self.time_zone) {
+            (DataType::Datetime(_, current), Some(time_zone)) => {
+                let expr = match current {
+                    Some(_) => col(name),
+                    None => col(name).dt().replace_time_zone(
+                        Some("UTC".into()),
+                        lit("raise"),
+                        NonExistent::Raise,
+                    ),
+                };
+                expr.dt().convert_time_zone(time_zone.as_str().into())
+            },
+            _ => col(name),
+        }
+    }
+
+    /// Returns the name and expression of every output column of a datetime column.
+    fn component_columns(&self, name: &str, dtype: &DataType) -> Vec<(String, Expr)> {
+        let source = self.source_expr(name, dtype);
+        let mut columns: Vec<(String, Expr)> = Vec::new();
+
+        for component in &self.components {
+            if component.is_time_of_day() && dtype == &DataType::Date {
+                continue;
+            }
+
+            let column = format!("{name}_{}", component.suffix());
+            let expr = component.expr(source.clone());
+            columns.push((column.clone(), expr.clone()));
+
+            if let (true, Some(period)) = (self.cyclical, component.period()) {
+                let (sin, cos) = sin_cos_exprs(expr, lit(period));
+                columns.push((format!("{column}_sin"), sin));
+                columns.push((format!("{column}_cos"), cos));
+            }
+        }
+
+        columns
+    }
+
+    /// Learns which columns hold dates or datetimes.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        validate_not_empty_df(df)?;
+        if self.components.is_empty() {
+            return Err(TransformationError::InvalidParameterError(
+                "at least one component must be extracted".to_string(),
+            ));
+        }
+
+        let datetime_columns = df
+            .get_columns()
+            .iter()
+            .filter(|col| matches!(col.dtype(), DataType::Date | DataType::Datetime(..)))
+            .map(|col| col.name().to_string())
+            .collect();
+
+        self.datetime_columns = Some(datetime_columns);
+
+        Ok(())
+    }
+
+    /// Replaces the fitted columns with their components. Other columns are passed through.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        let datetime_columns = self
+            .datetime_columns
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let fitted: Vec<&str> = datetime_columns.iter().map(|name| name.as_str()).collect();
+        validate_columns_present(df, &fitted)?;
+
+        let mismatched_cols: Vec<&str> = datetime_columns
+            .iter()
+            .filter(|name| {
+                !matches!(
+                    df.column(name).map(|col| col.dtype()),
+                    Ok(DataType::Date | DataType::Datetime(..))
+                )
+            })
+            .map(|name| name.as_str())
+            .collect();
+        if !mismatched_cols.is_empty() {
+            Err(ValidationError::DtypeMismatchError(
+                mismatched_cols.join(", "),
+            ))?;
+        }
+
+        let mut exprs: Vec<Expr> = Vec::new();
+        for column in df.get_columns() {
+            let name = column.name().as_str();
+            if !datetime_columns.iter().any(|fitted| fitted == name) {
+                exprs.push(col(name));
+                continue;
+            }
+
+            if self.keep_original {
+                exprs.push(col(name));
+            }
+            exprs.extend(
+                self.component_columns(name, column.dtype())
+                    .into_iter()
+                    .map(|(output, expr)| expr.alias(output)),
+            );
+        }
+
+        let expanded_df = df.clone().lazy().select(exprs).collect()?;
+
+        Ok(expanded_df)
+    }
+
+    /// Learns the datetime columns and returns the expanded DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.datetime_columns = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    // 2024-01-06 23:30 (a Saturday) and 2024-03-15 08:00 (a Friday), in UTC.
+    const TIMESTAMPS_MS: [i64; 2] = [1_704_583_800_000, 1_710_489_600_000];
+
+    fn create_df() -> DataFrame {
+        let ts = Series::new("ts".into(), TIMESTAMPS_MS)
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let date = Series::new("date".into(), [19_728i32, 19_797])
+            .cast(&DataType::Date)
+            .unwrap();
+        let value = Series::new("value".into(), [1.0f64, 2.0]);
+
+        DataFrame::new(vec![ts.into(), date.into(), value.into()]).unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let features = DatetimeFeatures::new();
+        assert_eq!(features.components.len(), 7);
+        assert!(!features.cyclical);
+        assert!(features.time_zone.is_none());
+        assert!(!features.keep_original);
+        assert!(features.datetime_columns().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit() {
+        let mut features = DatetimeFeatures::new();
+        features.fit(&create_df()).unwrap();
+        assert_eq!(features.datetime_columns().unwrap(), ["ts", "date"]);
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut features = DatetimeFeatures::new();
+        let expanded_df = features.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "ts_year" => [2024i32, 2024],
+            "ts_month" => [1i32, 3],
+            "ts_day" => [6i32, 15],
+            "ts_day_of_week" => [6i32, 5],
+            "ts_day_of_year" => [6i32, 75],
+            "ts_hour" => [23i32, 8],
+            "ts_is_weekend" => [1u8, 0],
+            "date_year" => [2024i32, 2024],
+            "date_month" => [1i32, 3],
+            "date_day" => [6i32, 15],
+            "date_day_of_week" => [6i32, 5],
+            "date_day_of_year" => [6i32, 75],
+            "date_is_weekend" => [1u8, 0],
+            "value" => [1.0f64, 2.0]
+        ]
+        .unwrap();
+        assert_eq!(expanded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_time_zone() {
+        let mut features = DatetimeFeatures::new()
+            .with_components(vec![
+                DatetimeComponent::Day,
+                DatetimeComponent::Hour,
+                DatetimeComponent::IsWeekend,
+            ])
+            .with_time_zone("Asia/Tokyo");
+        let expanded_df = features
+            .fit_transform(&create_df().select(["ts"]).unwrap())
+            .unwrap();
+
+        // Tokyo is 9 hours ahead of UTC, so the first timestamp falls on Sunday morning.
+        let expected = df![
+            "ts_day" => [7i32, 15],
+            "ts_hour" => [8i32, 17],
+            "ts_is_weekend" => [1u8, 0]
+        ]
+        .unwrap();
+        assert_eq!(expanded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_cyclical_and_keep_original() {
+        let mut features = DatetimeFeatures::new()
+            .with_components(vec![DatetimeComponent::Year, DatetimeComponent::Hour])
+            .with_cyclical(true)
+            .with_keep_original(true);
+        let df = create_df().select(["ts"]).unwrap();
+        let expanded_df = features.fit_transform(&df).unwrap();
+
+        assert_eq!(
+            expanded_df.get_column_names(),
+            ["ts", "ts_year", "ts_hour", "ts_hour_sin", "ts_hour_cos"]
+        );
+
+        let hour_sin = expanded_df.column("ts_hour_sin").unwrap().f64().unwrap();
+        let hour_cos = expanded_df.column("ts_hour_cos").unwrap().f64().unwrap();
+        assert_abs_diff_eq!(
+            hour_sin.get(0).unwrap(),
+            (2.0 * PI * 23.0 / 24.0).sin(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            hour_cos.get(1).unwrap(),
+            (2.0 * PI * 8.0 / 24.0).cos(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_transform_dtype_mismatch() {
+        let mut features = DatetimeFeatures::new();
+        features.fit(&create_df()).unwrap();
+
+        let df = df![
+            "ts" => ["2024-01-06", "2024-03-15"],
+            "date" => ["2024-01-06", "2024-03-15"]
+        ]
+        .unwrap();
+        assert!(matches!(
+            features.transform(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::DtypeMismatchError(ref cols)
+            )) if cols == "ts, date"
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let features = DatetimeFeatures::new();
+        assert!(matches!(
+            features.transform(&create_df()),
+            Err(TransformationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut features = DatetimeFeatures::new();
+        features.fit(&create_df()).unwrap();
+
+        features.reset();
+        assert!(features.datetime_columns().is_none());
+    }
+}
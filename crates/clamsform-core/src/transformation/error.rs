@@ -0,0 +1,32 @@
+use polars::prelude::*;
+use thiserror::Error;
+
+use crate::scaling::standardization::error::ScalingError;
+use crate::validation::error::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum TransformationError {
+    #[error(
+        "The transformer has not been fitted. \
+        Call `fit` before attempting to transform a DataFrame."
+    )]
+    NotFittedError,
+
+    #[error(
+        "The generated column name(s) {0} would be produced more than once. \
+        Rename the input columns so that every generated name is unique."
+    )]
+    DuplicateFeatureNamesError(String),
+
+    #[error("Invalid transformer parameter: {0}")]
+    InvalidParameterError(String),
+
+    #[error(transparent)]
+    ScalingError(#[from] ScalingError),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
@@ -0,0 +1,450 @@
+use std::sync::Arc;
+
+use polars::prelude::*;
+
+use super::error::*;
+use super::traits::InvertibleTransformer;
+use crate::validation::error::*;
+
+/// A user-supplied transformation of a column expression.
+pub type ExprFn = Arc<dyn Fn(Expr) -> Expr + Send + Sync>;
+
+/// A built-in function with a paired inverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinFunction {
+    /// The natural logarithm, inverted by `exp`. Defined for positive values.
+    Log,
+    /// `ln(1 + x)`, inverted by `exp(x) - 1`. Defined for values greater than -1.
+    Log1p,
+    /// The square root, inverted by squaring. Defined for non-negative values.
+    Sqrt,
+    /// `1 / x`, its own inverse. Defined for non-zero values.
+    Reciprocal,
+    /// The exponential, inverted by `log`.
+    Exp,
+}
+
+impl BuiltinFunction {
+    fn apply(&self, expr: Expr) -> Expr {
+        let expr = expr.cast(DataType::Float64);
+        match self {
+            BuiltinFunction::Log => expr.log(std::f64::consts::E),
+            BuiltinFunction::Log1p => expr.log1p(),
+            BuiltinFunction::Sqrt => expr.sqrt(),
+            BuiltinFunction::Reciprocal => lit(1.0) / expr,
+            BuiltinFunction::Exp => expr.exp(),
+        }
+    }
+
+    fn apply_inverse(&self, expr: Expr) -> Expr {
+        let expr = expr.cast(DataType::Float64);
+        match self {
+            BuiltinFunction::Log => expr.exp(),
+            BuiltinFunction::Log1p => expr.exp() - lit(1.0),
+            BuiltinFunction::Sqrt => expr.pow(2.0),
+            BuiltinFunction::Reciprocal => lit(1.0) / expr,
+            BuiltinFunction::Exp => expr.log(std::f64::consts::E),
+        }
+    }
+
+    /// Validates that every value lies in the domain of the function.
+    fn validate_domain(&self, df: &DataFrame) -> Result<(), ValidationError> {
+        match self {
+            BuiltinFunction::Log => validate_values_above(df, 0.0),
+            BuiltinFunction::Log1p => validate_values_above(df, -1.0),
+            BuiltinFunction::Sqrt => validate_non_negative_values(df),
+            BuiltinFunction::Reciprocal => validate_non_zero_values(df),
+            BuiltinFunction::Exp => Ok(()),
+        }
+    }
+
+    /// Validates that every value lies in the domain of the inverse.
+    fn validate_inverse_domain(&self, df: &DataFrame) -> Result<(), ValidationError> {
+        match self {
+            BuiltinFunction::Log | BuiltinFunction::Log1p => Ok(()),
+            BuiltinFunction::Sqrt => validate_non_negative_values(df),
+            BuiltinFunction::Reciprocal => validate_non_zero_values(df),
+            BuiltinFunction::Exp => validate_values_above(df, 0.0),
+        }
+    }
+}
+
+enum TransformFunction {
+    Builtin(BuiltinFunction),
+    Custom(ExprFn),
+}
+
+/// Applies an element-wise function, such as `log1p`, to numeric columns in place.
+///
+/// Built-in functions check their domain and come with a paired inverse. Custom functions
+/// are polars expressions built from the column expression, and can be given an inverse
+/// with `with_inverse_fn`.
+pub struct FunctionTransformer {
+    function: TransformFunction,
+    inverse_fn: Option<ExprFn>,
+    selected_columns: Option<Vec<String>>,
+    input_columns: Option<Vec<String>>,
+}
+
+impl Default for FunctionTransformer {
+    fn default() -> Self {
+        Self::new(BuiltinFunction::Log1p)
+    }
+}
+
+impl FunctionTransformer {
+    pub fn new(function: BuiltinFunction) -> Self {
+        FunctionTransformer {
+            function: TransformFunction::Builtin(function),
+            inverse_fn: None,
+            selected_columns: None,
+            input_columns: None,
+        }
+    }
+
+    /// Creates a transformer applying a custom expression, such as `|x| x.abs().sqrt()`.
+    pub fn from_fn(func: impl Fn(Expr) -> Expr + Send + Sync + 'static) -> Self {
+        FunctionTransformer {
+            function: TransformFunction::Custom(Arc::new(func)),
+            inverse_fn: None,
+            selected_columns: None,
+            input_columns: None,
+        }
+    }
+
+    /// Sets the expression used by `inverse_transform`, replacing the paired inverse of a
+    /// built-in function.
+    pub fn with_inverse_fn(
+        mut self,
+        inverse: impl Fn(Expr) -> Expr + Send + Sync + 'static,
+    ) -> Self {
+        self.inverse_fn = Some(Arc::new(inverse));
+        self
+    }
+
+    /// Restricts the function to the given columns. Defaults to every column.
+    pub fn with_columns(mut self, columns: Vec<String>) -> Self {
+        self.selected_columns = Some(columns);
+        self
+    }
+
+    /// The built-in function, or `None` for a custom function.
+    pub fn builtin(&self) -> Option<BuiltinFunction> {
+        match self.function {
+            TransformFunction::Builtin(function) => Some(function),
+            TransformFunction::Custom(_) => None,
+        }
+    }
+
+    /// The columns the transformer was fitted on.
+    pub fn input_columns(&self) -> Option<&[String]> {
+        self.input_columns.as_deref()
+    }
+
+    /// Learns the columns to transform.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        let columns: Vec<String> = match &self.selected_columns {
+            Some(columns) => columns.clone(),
+            None => df
+                .get_column_names()
+                .into_iter()
+                .map(|name| name.to_string())
+                .collect(),
+        };
+        let names: Vec<&str> = columns.iter().map(|name| name.as_str()).collect();
+        validate_fitted_columns(df, &names)?;
+
+        self.input_columns = Some(columns);
+
+        Ok(())
+    }
+
+    /// Replaces the fitted columns with the function of their values.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        let columns = self.validated_columns(df)?;
+        if let TransformFunction::Builtin(function) = &self.function {
+            function.validate_domain(&df.select(columns.iter().copied())?)?;
+        }
+
+        let exprs: Vec<Expr> = columns
+            .iter()
+            .map(|name| {
+                match &self.function {
+                    TransformFunction::Builtin(function) => function.apply(col(*name)),
+                    TransformFunction::Custom(func) => func(col(*name)),
+                }
+                .alias(*name)
+            })
+            .collect();
+
+        let transformed_df = df.clone().lazy().with_columns(exprs).collect()?;
+
+        Ok(transformed_df)
+    }
+
+    /// Replaces the fitted columns with the inverse of the function of their values.
+    pub fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        let columns = self.validated_columns(df)?;
+
+        let exprs: Vec<Expr> = match (&self.inverse_fn, &self.function) {
+            (Some(inverse), _) => columns
+                .iter()
+                .map(|name| inverse(col(*name)).alias(*name))
+                .collect(),
+            (None, TransformFunction::Builtin(function)) => {
+                function.validate_inverse_domain(&df.select(columns.iter().copied())?)?;
+                columns
+                    .iter()
+                    .map(|name| function.apply_inverse(col(*name)).alias(*name))
+                    .collect()
+            },
+            (None, TransformFunction::Custom(_)) => {
+                return Err(TransformationError::InvalidParameterError(
+                    "a custom function needs an inverse to be inverted".to_string(),
+                ));
+            },
+        };
+
+        let inverted_df = df.clone().lazy().with_columns(exprs).collect()?;
+
+        Ok(inverted_df)
+    }
+
+    /// Learns the columns to transform and returns the transformed DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.input_columns = None;
+    }
+
+    fn validated_columns(&self, df: &DataFrame) -> Result<Vec<&str>, TransformationError> {
+        let columns: Vec<&str> = self
+            .input_columns
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?
+            .iter()
+            .map(|name| name.as_str())
+            .collect();
+        validate_fitted_columns(df, &columns)?;
+
+        Ok(columns)
+    }
+}
+
+impl InvertibleTransformer for FunctionTransformer {
+    fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        FunctionTransformer::fit(self, df)
+    }
+
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        FunctionTransformer::transform(self, df)
+    }
+
+    fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        FunctionTransformer::inverse_transform(self, df)
+    }
+
+    fn reset(&mut self) {
+        FunctionTransformer::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "a" => [1.0f64, 2.0, 4.0],
+            "b" => [0.5f64, 1.0, 10.0]
+        ]
+        .unwrap()
+    }
+
+    fn assert_frame_close(left: &DataFrame, right: &DataFrame) {
+        assert_eq!(left.get_column_names(), right.get_column_names());
+        for (left, right) in left.get_columns().iter().zip(right.get_columns()) {
+            let left = left.f64().unwrap();
+            let right = right.f64().unwrap();
+            for (l, r) in left.into_no_null_iter().zip(right.into_no_null_iter()) {
+                assert_abs_diff_eq!(l, r, epsilon = 1e-12);
+            }
+        }
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let transformer = FunctionTransformer::default();
+        assert_eq!(transformer.builtin(), Some(BuiltinFunction::Log1p));
+        assert!(transformer.inverse_fn.is_none());
+        assert!(transformer.input_columns().is_none());
+
+        let transformer = FunctionTransformer::from_fn(|x| x * lit(2.0));
+        assert!(transformer.builtin().is_none());
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_builtins() {
+        let df = create_df();
+        let cases = [
+            (BuiltinFunction::Log, [0.0, 2.0f64.ln(), 4.0f64.ln()]),
+            (
+                BuiltinFunction::Log1p,
+                [2.0f64.ln(), 3.0f64.ln(), 5.0f64.ln()],
+            ),
+            (BuiltinFunction::Sqrt, [1.0, 2.0f64.sqrt(), 2.0]),
+            (BuiltinFunction::Reciprocal, [1.0, 0.5, 0.25]),
+            (
+                BuiltinFunction::Exp,
+                [1.0f64.exp(), 2.0f64.exp(), 4.0f64.exp()],
+            ),
+        ];
+
+        for (function, expected_a) in cases {
+            let mut transformer =
+                FunctionTransformer::new(function).with_columns(vec!["a".to_string()]);
+            let transformed_df = transformer.fit_transform(&df).unwrap();
+
+            let expected = df![
+                "a" => expected_a,
+                "b" => [0.5f64, 1.0, 10.0]
+            ]
+            .unwrap();
+            assert_frame_close(&transformed_df, &expected);
+        }
+    }
+
+    #[test]
+    fn test_transform_custom() {
+        let mut transformer = FunctionTransformer::from_fn(|x| x * lit(2.0) + lit(1.0));
+        let transformed_df = transformer.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "a" => [3.0f64, 5.0, 9.0],
+            "b" => [2.0f64, 3.0, 21.0]
+        ]
+        .unwrap();
+        assert_eq!(transformed_df, expected);
+    }
+
+    #[test]
+    fn test_transform_domain_errors() {
+        let df = df![
+            "pos" => [1.0f64, 2.0],
+            "zero" => [0.0f64, 1.0],
+            "neg" => [-1.0f64, 1.0]
+        ]
+        .unwrap();
+
+        let mut transformer = FunctionTransformer::new(BuiltinFunction::Log);
+        transformer.fit(&df).unwrap();
+        assert!(matches!(
+            transformer.transform(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::ValuesBelowBoundError(ref cols, _)
+            )) if cols == "zero, neg"
+        ));
+
+        let mut transformer = FunctionTransformer::new(BuiltinFunction::Log1p);
+        transformer.fit(&df).unwrap();
+        assert!(matches!(
+            transformer.transform(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::ValuesBelowBoundError(ref cols, bound)
+            )) if cols == "neg" && bound == -1.0
+        ));
+
+        let mut transformer = FunctionTransformer::new(BuiltinFunction::Sqrt);
+        transformer.fit(&df).unwrap();
+        assert!(matches!(
+            transformer.transform(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::NegativeValuesError(ref cols)
+            )) if cols == "neg"
+        ));
+
+        let mut transformer = FunctionTransformer::new(BuiltinFunction::Reciprocal);
+        transformer.fit(&df).unwrap();
+        assert!(matches!(
+            transformer.transform(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::ZeroValuesError(ref cols)
+            )) if cols == "zero"
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let transformer = FunctionTransformer::default();
+        assert!(matches!(
+            transformer.transform(&create_df()),
+            Err(TransformationError::NotFittedError)
+        ));
+    }
+
+    // Test inverse_transform method
+    #[test]
+    fn test_inverse_transform_builtins() {
+        let df = create_df();
+        for function in [
+            BuiltinFunction::Log,
+            BuiltinFunction::Log1p,
+            BuiltinFunction::Sqrt,
+            BuiltinFunction::Reciprocal,
+            BuiltinFunction::Exp,
+        ] {
+            let mut transformer = FunctionTransformer::new(function);
+            let transformed_df = transformer.fit_transform(&df).unwrap();
+            let inverted_df = transformer.inverse_transform(&transformed_df).unwrap();
+            assert_frame_close(&inverted_df, &df);
+        }
+    }
+
+    #[test]
+    fn test_inverse_transform_custom() {
+        let mut transformer = FunctionTransformer::from_fn(|x| x * lit(2.0));
+        let transformed_df = transformer.fit_transform(&create_df()).unwrap();
+        assert!(matches!(
+            transformer.inverse_transform(&transformed_df),
+            Err(TransformationError::InvalidParameterError(_))
+        ));
+
+        let transformer = transformer.with_inverse_fn(|x| x / lit(2.0));
+        assert_eq!(
+            transformer.inverse_transform(&transformed_df).unwrap(),
+            create_df()
+        );
+    }
+
+    #[test]
+    fn test_inverse_transform_domain_error() {
+        let mut transformer = FunctionTransformer::new(BuiltinFunction::Exp);
+        transformer.fit(&create_df()).unwrap();
+
+        let df = df!["a" => [1.0f64, -1.0], "b" => [1.0f64, 1.0]].unwrap();
+        assert!(matches!(
+            transformer.inverse_transform(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::ValuesBelowBoundError(ref cols, _)
+            )) if cols == "a"
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut transformer = FunctionTransformer::default();
+        transformer.fit(&create_df()).unwrap();
+
+        transformer.reset();
+        assert!(transformer.input_columns().is_none());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod cyclical;
+pub mod datetime;
+pub mod error;
+pub mod function;
+pub mod polynomial;
+pub mod spline;
+pub mod target;
+pub mod traits;
+pub mod winsorizer;
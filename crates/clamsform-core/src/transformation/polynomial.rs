@@ -0,0 +1,410 @@
+use std::collections::HashSet;
+
+use polars::prelude::*;
+
+use super::error::*;
+use crate::validation::error::*;
+
+/// Generates the products of numeric columns up to a given degree.
+///
+/// Terms are named after their factors, such as `a^2` or `a*b`. The transformed DataFrame
+/// holds the input columns followed by the generated terms of degree 2 or more, and the
+/// constant `1` column when the bias is included.
+pub struct PolynomialFeatures {
+    degree: usize,
+    interaction_only: bool,
+    include_bias: bool,
+    selected_columns: Option<Vec<String>>,
+    input_columns: Option<Vec<String>>,
+}
+
+impl Default for PolynomialFeatures {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl PolynomialFeatures {
+    pub fn new(degree: usize) -> Self {
+        PolynomialFeatures {
+            degree,
+            interaction_only: false,
+            include_bias: false,
+            selected_columns: None,
+            input_columns: None,
+        }
+    }
+
+    /// Only generates products of distinct columns, such as `a*b` but not `a^2`.
+    pub fn with_interaction_only(mut self, interaction_only: bool) -> Self {
+        self.interaction_only = interaction_only;
+        self
+    }
+
+    /// Adds a constant `1` column.
+    pub fn with_include_bias(mut self, include_bias: bool) -> Self {
+        self.include_bias = include_bias;
+        self
+    }
+
+    /// Restricts the expansion to the given columns. Defaults to every column.
+    pub fn with_columns(mut self, columns: Vec<String>) -> Self {
+        self.selected_columns = Some(columns);
+        self
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// The columns the transformer was fitted on.
+    pub fn input_columns(&self) -> Option<&[String]> {
+        self.input_columns.as_deref()
+    }
+
+    /// Returns the names of every term, from the bias and the degree 1 terms upwards.
+    pub fn feature_names_out(&self) -> Option<Vec<String>> {
+        self.input_columns.as_ref().map(|columns| {
+            self.terms(columns)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        })
+    }
+
+    /// Returns the name and expression of every term, in output order.
+    fn terms(&self, columns: &[String]) -> Vec<(String, Expr)> {
+        let mut terms: Vec<(String, Expr)> = Vec::new();
+        if self.include_bias {
+            terms.push(("1".to_string(), lit(1.0f64)));
+        }
+
+        for degree in 1..=self.degree {
+            for factors in combinations(columns.len(), degree, !self.interaction_only) {
+                let expr = factors
+                    .iter()
+                    .map(|&i| col(columns[i].as_str()).cast(DataType::Float64))
+                    .reduce(|acc, factor| acc * factor)
+                    .unwrap();
+                terms.push((term_name(columns, &factors), expr));
+            }
+        }
+
+        terms
+    }
+
+    /// Returns the generated terms, skipping the degree 1 terms that are the input columns.
+    fn new_terms(&self, columns: &[String]) -> Vec<(String, Expr)> {
+        self.terms(columns)
+            .into_iter()
+            .filter(|(name, _)| !columns.contains(name))
+            .collect()
+    }
+
+    /// Learns the columns to expand, rejecting generated names that already name a column.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        if self.degree == 0 {
+            return Err(TransformationError::InvalidParameterError(
+                "degree must be at least 1".to_string(),
+            ));
+        }
+
+        let columns: Vec<String> = match &self.selected_columns {
+            Some(columns) => columns.clone(),
+            None => df
+                .get_column_names()
+                .into_iter()
+                .map(|name| name.to_string())
+                .collect(),
+        };
+        let names: Vec<&str> = columns.iter().map(|name| name.as_str()).collect();
+        validate_fitted_columns(df, &names)?;
+
+        let mut seen: HashSet<String> = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect();
+        let mut duplicates: Vec<String> = self
+            .new_terms(&columns)
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| !seen.insert(name.clone()))
+            .collect();
+        if !duplicates.is_empty() {
+            duplicates.sort();
+            duplicates.dedup();
+            return Err(TransformationError::DuplicateFeatureNamesError(
+                duplicates.join(", "),
+            ));
+        }
+
+        self.input_columns = Some(columns);
+
+        Ok(())
+    }
+
+    /// Appends the generated terms to a lazy frame.
+    pub fn transform_lazy(&self, lf: LazyFrame) -> Result<LazyFrame, TransformationError> {
+        let columns = self
+            .input_columns
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let new_terms: Vec<Expr> = self
+            .new_terms(columns)
+            .into_iter()
+            .map(|(name, expr)| expr.alias(name))
+            .collect();
+
+        Ok(lf.with_columns(new_terms))
+    }
+
+    /// Appends the generated terms to the DataFrame.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        let columns = self
+            .input_columns
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let names: Vec<&str> = columns.iter().map(|name| name.as_str()).collect();
+        validate_fitted_columns(df, &names)?;
+
+        Ok(self.transform_lazy(df.clone().lazy())?.collect()?)
+    }
+
+    /// Learns the columns to expand and returns the expanded DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.input_columns = None;
+    }
+}
+
+/// Returns the non-decreasing sequences of `degree` indices below `n`, in lexical order.
+///
+/// Without repetition, the sequences are strictly increasing.
+fn combinations(n: usize, degree: usize, repetition: bool) -> Vec<Vec<usize>> {
+    fn extend(
+        n: usize,
+        degree: usize,
+        repetition: bool,
+        current: &mut Vec<usize>,
+        out: &mut Vec<Vec<usize>>,
+    ) {
+        if current.len() == degree {
+            out.push(current.clone());
+            return;
+        }
+
+        let start = match current.last() {
+            Some(&last) if repetition => last,
+            Some(&last) => last + 1,
+            None => 0,
+        };
+        for i in start..n {
+            current.push(i);
+            extend(n, degree, repetition, current, out);
+            current.pop();
+        }
+    }
+
+    let mut out = Vec::new();
+    extend(
+        n,
+        degree,
+        repetition,
+        &mut Vec::with_capacity(degree),
+        &mut out,
+    );
+    out
+}
+
+/// Names a term after its factors, grouping repeated factors into powers.
+fn term_name(columns: &[String], factors: &[usize]) -> String {
+    factors
+        .chunk_by(|a, b| a == b)
+        .map(|group| match group.len() {
+            1 => columns[group[0]].clone(),
+            power => format!("{}^{power}", columns[group[0]]),
+        })
+        .collect::<Vec<_>>()
+        .join("*")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::error::ValidationError;
+
+    fn create_df() -> DataFrame {
+        df![
+            "a" => [1.0f64, 2.0, 3.0],
+            "b" => [4.0f64, 5.0, 6.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let polynomial = PolynomialFeatures::default();
+        assert_eq!(polynomial.degree(), 2);
+        assert!(!polynomial.interaction_only);
+        assert!(!polynomial.include_bias);
+        assert!(polynomial.input_columns().is_none());
+        assert!(polynomial.feature_names_out().is_none());
+    }
+
+    #[test]
+    fn test_combinations() {
+        assert_eq!(
+            combinations(2, 2, true),
+            [vec![0, 0], vec![0, 1], vec![1, 1]]
+        );
+        assert_eq!(
+            combinations(3, 2, false),
+            [vec![0, 1], vec![0, 2], vec![1, 2]]
+        );
+        assert!(combinations(2, 3, false).is_empty());
+    }
+
+    // Test feature_names_out method
+    #[test]
+    fn test_feature_names_out() {
+        let mut polynomial = PolynomialFeatures::new(3).with_include_bias(true);
+        polynomial.fit(&create_df()).unwrap();
+
+        assert_eq!(
+            polynomial.feature_names_out().unwrap(),
+            ["1", "a", "b", "a^2", "a*b", "b^2", "a^3", "a^2*b", "a*b^2", "b^3"]
+        );
+    }
+
+    #[test]
+    fn test_feature_names_out_interaction_only() {
+        let df = df![
+            "a" => [1.0f64],
+            "b" => [2.0f64],
+            "c" => [3.0f64]
+        ]
+        .unwrap();
+        let mut polynomial = PolynomialFeatures::new(3).with_interaction_only(true);
+        polynomial.fit(&df).unwrap();
+
+        assert_eq!(
+            polynomial.feature_names_out().unwrap(),
+            ["a", "b", "c", "a*b", "a*c", "b*c", "a*b*c"]
+        );
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut polynomial = PolynomialFeatures::new(2);
+        let expanded_df = polynomial.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "a" => [1.0f64, 2.0, 3.0],
+            "b" => [4.0f64, 5.0, 6.0],
+            "a^2" => [1.0f64, 4.0, 9.0],
+            "a*b" => [4.0f64, 10.0, 18.0],
+            "b^2" => [16.0f64, 25.0, 36.0]
+        ]
+        .unwrap();
+        assert_eq!(expanded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_selected_columns() {
+        let df = df![
+            "a" => [1.0f64, 2.0],
+            "b" => [3.0f64, 4.0],
+            "label" => ["x", "y"]
+        ]
+        .unwrap();
+        let mut polynomial = PolynomialFeatures::new(2)
+            .with_interaction_only(true)
+            .with_include_bias(true)
+            .with_columns(vec!["a".to_string(), "b".to_string()]);
+        let expanded_df = polynomial.fit_transform(&df).unwrap();
+
+        let expected = df![
+            "a" => [1.0f64, 2.0],
+            "b" => [3.0f64, 4.0],
+            "label" => ["x", "y"],
+            "1" => [1.0f64, 1.0],
+            "a*b" => [3.0f64, 8.0]
+        ]
+        .unwrap();
+        assert_eq!(expanded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_lazy_friendly() {
+        let mut polynomial = PolynomialFeatures::new(2);
+        polynomial.fit(&create_df()).unwrap();
+
+        let lazy_df = polynomial
+            .transform_lazy(create_df().lazy())
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(lazy_df, polynomial.transform(&create_df()).unwrap());
+        assert_eq!(lazy_df.width(), 5);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut polynomial = PolynomialFeatures::new(0);
+        assert!(matches!(
+            polynomial.fit(&create_df()),
+            Err(TransformationError::InvalidParameterError(_))
+        ));
+
+        let mut polynomial = PolynomialFeatures::new(2).with_columns(vec!["c".to_string()]);
+        assert!(matches!(
+            polynomial.fit(&create_df()),
+            Err(TransformationError::ValidationError(
+                ValidationError::MissingColumnsError(ref cols)
+            )) if cols == "c"
+        ));
+
+        let df = df![
+            "a" => [1.0f64, 2.0],
+            "b" => [3.0f64, 4.0],
+            "a*b" => [3.0f64, 8.0]
+        ]
+        .unwrap();
+        let mut polynomial =
+            PolynomialFeatures::new(2).with_columns(vec!["a".to_string(), "b".to_string()]);
+        assert!(matches!(
+            polynomial.fit(&df),
+            Err(TransformationError::DuplicateFeatureNamesError(ref names)) if names == "a*b"
+        ));
+        assert!(polynomial.input_columns().is_none());
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let polynomial = PolynomialFeatures::new(2);
+        assert!(matches!(
+            polynomial.transform(&create_df()),
+            Err(TransformationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut polynomial = PolynomialFeatures::new(2);
+        polynomial.fit(&create_df()).unwrap();
+
+        polynomial.reset();
+        assert!(polynomial.input_columns().is_none());
+    }
+}
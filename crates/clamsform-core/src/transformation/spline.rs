@@ -0,0 +1,542 @@
+use polars::prelude::*;
+
+use super::error::*;
+use crate::validation::error::*;
+
+/// How the knots are placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnotStrategy {
+    /// Knots evenly spaced between the minimum and the maximum.
+    Uniform,
+    /// Knots at evenly spaced quantiles.
+    Quantile,
+}
+
+/// How values outside the range of the knots are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplineExtrapolation {
+    /// Fail with `ValidationError::OutOfRangeError`.
+    Error,
+    /// Use the basis values at the nearest boundary knot.
+    Constant,
+    /// Continue the basis functions linearly from the nearest boundary knot.
+    Linear,
+    /// Wrap the values around the period spanned by the knots.
+    Periodic,
+}
+
+/// Expands numeric columns into B-spline basis columns named `<col>_sp_<i>`.
+///
+/// With `n_knots` knots and degree `d`, every column is replaced with `n_knots + d - 1`
+/// basis columns, or `n_knots - 1` with periodic extrapolation. Within the range of the
+/// knots, the basis values of a row sum to 1.
+pub struct SplineTransformer {
+    n_knots: usize,
+    degree: usize,
+    strategy: KnotStrategy,
+    extrapolation: SplineExtrapolation,
+    knots: Option<DataFrame>,
+}
+
+impl Default for SplineTransformer {
+    fn default() -> Self {
+        Self::new(5, 3)
+    }
+}
+
+impl SplineTransformer {
+    pub fn new(n_knots: usize, degree: usize) -> Self {
+        SplineTransformer {
+            n_knots,
+            degree,
+            strategy: KnotStrategy::Uniform,
+            extrapolation: SplineExtrapolation::Constant,
+            knots: None,
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: KnotStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn with_extrapolation(mut self, extrapolation: SplineExtrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    pub fn n_knots(&self) -> usize {
+        self.n_knots
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// The knots of every fitted column, in increasing order.
+    pub fn knots(&self) -> Option<&DataFrame> {
+        self.knots.as_ref()
+    }
+
+    fn n_splines(&self) -> usize {
+        match self.extrapolation {
+            SplineExtrapolation::Periodic => self.n_knots - 1,
+            _ => self.n_knots + self.degree - 1,
+        }
+    }
+
+    /// Returns the names of the output columns of the fitted columns.
+    pub fn feature_names_out(&self) -> Option<Vec<String>> {
+        self.knots.as_ref().map(|knots| {
+            knots
+                .get_column_names()
+                .into_iter()
+                .flat_map(|name| (0..self.n_splines()).map(move |i| format!("{name}_sp_{i}")))
+                .collect()
+        })
+    }
+
+    /// Extends the knots of a column by `degree` knots on both sides.
+    fn extended_knots(&self, base: &[f64]) -> Vec<f64> {
+        let d = self.degree;
+        let (first, last) = (base[0], base[base.len() - 1]);
+
+        let (before, after): (Vec<f64>, Vec<f64>) = match self.extrapolation {
+            SplineExtrapolation::Periodic => {
+                let period = last - first;
+                let m = base.len() - 1;
+                (
+                    (1..=d)
+                        .rev()
+                        .map(|i| base[m - 1 - (i - 1) % m] - period * ((i - 1) / m + 1) as f64)
+                        .collect(),
+                    (1..=d)
+                        .map(|i| base[1 + (i - 1) % m] + period * ((i - 1) / m + 1) as f64)
+                        .collect(),
+                )
+            },
+            _ => {
+                let dist_min = base[1] - first;
+                let dist_max = last - base[base.len() - 2];
+                (
+                    (1..=d).rev().map(|i| first - dist_min * i as f64).collect(),
+                    (1..=d).map(|i| last + dist_max * i as f64).collect(),
+                )
+            },
+        };
+
+        before
+            .into_iter()
+            .chain(base.iter().copied())
+            .chain(after)
+            .collect()
+    }
+
+    /// Evaluates the basis of a column at every value.
+    fn basis_rows(&self, base: &[f64], values: &[f64]) -> Vec<Vec<f64>> {
+        let knots = self.extended_knots(base);
+        let (first, last) = (base[0], base[base.len() - 1]);
+        // The last knot interval of the base knots, which holds the right boundary.
+        let last_span = self.degree + base.len() - 2;
+
+        values
+            .iter()
+            .map(|&x| {
+                let full = match self.extrapolation {
+                    SplineExtrapolation::Periodic => {
+                        let wrapped = first + (x - first).rem_euclid(last - first);
+                        bspline_basis(&knots, self.degree, wrapped, last_span)
+                    },
+                    SplineExtrapolation::Linear if x < first || x > last => {
+                        let boundary = if x < first { first } else { last };
+                        let basis = bspline_basis(&knots, self.degree, boundary, last_span);
+                        let slopes = bspline_derivative(&knots, self.degree, boundary, last_span);
+                        basis
+                            .iter()
+                            .zip(slopes)
+                            .map(|(value, slope)| value + slope * (x - boundary))
+                            .collect()
+                    },
+                    _ => bspline_basis(&knots, self.degree, x.clamp(first, last), last_span),
+                };
+
+                match self.extrapolation {
+                    SplineExtrapolation::Periodic => {
+                        let mut periodic = vec![0.0; self.n_splines()];
+                        for (i, value) in full.into_iter().enumerate() {
+                            periodic[i % self.n_splines()] += value;
+                        }
+                        periodic
+                    },
+                    _ => full,
+                }
+            })
+            .collect()
+    }
+
+    /// Learns the knots of every column.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        if self.n_knots < 2 {
+            return Err(TransformationError::InvalidParameterError(format!(
+                "n_knots must be at least 2, got {}",
+                self.n_knots
+            )));
+        }
+        validate_dataframe(df)?;
+
+        let positions: Vec<f64> = (0..self.n_knots)
+            .map(|i| i as f64 / (self.n_knots - 1) as f64)
+            .collect();
+
+        let mut knot_columns: Vec<Column> = Vec::with_capacity(df.width());
+        let mut invalid_cols: Vec<String> = Vec::new();
+        for name in df.get_column_names() {
+            let column = col(name.as_str()).cast(DataType::Float64);
+            let knot_exprs: Vec<Expr> = positions
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    match self.strategy {
+                        KnotStrategy::Uniform => {
+                            column.clone().min()
+                                + (column.clone().max() - column.clone().min()) * lit(*p)
+                        },
+                        KnotStrategy::Quantile => {
+                            column.clone().quantile(lit(*p), QuantileMethod::Linear)
+                        },
+                    }
+                    .alias(format!("knot_{i}"))
+                })
+                .collect();
+
+            let knot_df = df.clone().lazy().select(knot_exprs).collect()?;
+            let knots = knot_df
+                .get_columns()
+                .iter()
+                .map(|knot| Ok(knot.f64()?.get(0).unwrap_or(f64::NAN)))
+                .collect::<Result<Vec<f64>, TransformationError>>()?;
+
+            if knots.windows(2).any(|w| w[1] <= w[0]) {
+                invalid_cols.push(name.to_string());
+            }
+            knot_columns.push(Column::new(name.clone(), knots));
+        }
+        if !invalid_cols.is_empty() {
+            return Err(TransformationError::InvalidParameterError(format!(
+                "the knots of column(s) {} are not strictly increasing, \
+                consider fewer knots or the uniform strategy",
+                invalid_cols.join(", ")
+            )));
+        }
+
+        self.knots = Some(DataFrame::new(knot_columns)?);
+
+        Ok(())
+    }
+
+    /// Replaces the fitted columns with their basis columns. Other columns are passed through.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        let knots = self
+            .knots
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let fitted: Vec<&str> = knots
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.as_str())
+            .collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        let mut columns: Vec<Column> = Vec::new();
+        for column in df.get_columns() {
+            let Ok(column_knots) = knots.column(column.name()) else {
+                columns.push(column.clone());
+                continue;
+            };
+
+            let base: Vec<f64> = column_knots.f64()?.into_no_null_iter().collect();
+            if self.extrapolation == SplineExtrapolation::Error {
+                validate_value_range(
+                    &df.select([column.name().clone()])?,
+                    base[0],
+                    base[base.len() - 1],
+                )?;
+            }
+
+            let values: Vec<f64> = column
+                .cast(&DataType::Float64)?
+                .f64()?
+                .into_no_null_iter()
+                .collect();
+            let rows = self.basis_rows(&base, &values);
+
+            for i in 0..self.n_splines() {
+                let basis: Vec<f64> = rows.iter().map(|row| row[i]).collect();
+                columns.push(Column::new(
+                    format!("{}_sp_{i}", column.name()).into(),
+                    basis,
+                ));
+            }
+        }
+
+        Ok(DataFrame::new(columns)?)
+    }
+
+    /// Learns the knots and returns the expanded DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.knots = None;
+    }
+}
+
+/// Evaluates every B-spline of the given degree over the knots at `x`, with the
+/// Cox-de Boor recursion.
+///
+/// `x` belongs to the knot interval `[knots[i], knots[i + 1])`, except at `knots[last_span + 1]`,
+/// which belongs to `last_span` so that the right boundary is included.
+fn bspline_basis(knots: &[f64], degree: usize, x: f64, last_span: usize) -> Vec<f64> {
+    let span = if x >= knots[last_span + 1] {
+        last_span
+    } else {
+        (0..knots.len() - 1)
+            .find(|&i| knots[i] <= x && x < knots[i + 1])
+            .unwrap_or(last_span)
+    };
+
+    let mut basis: Vec<f64> = (0..knots.len() - 1)
+        .map(|i| if i == span { 1.0 } else { 0.0 })
+        .collect();
+
+    let ratio = |num: f64, den: f64| if den == 0.0 { 0.0 } else { num / den };
+    for p in 1..=degree {
+        basis = (0..knots.len() - p - 1)
+            .map(|i| {
+                ratio(x - knots[i], knots[i + p] - knots[i]) * basis[i]
+                    + ratio(knots[i + p + 1] - x, knots[i + p + 1] - knots[i + 1]) * basis[i + 1]
+            })
+            .collect();
+    }
+
+    basis
+}
+
+/// Evaluates the derivative of every B-spline of the given degree at `x`.
+fn bspline_derivative(knots: &[f64], degree: usize, x: f64, last_span: usize) -> Vec<f64> {
+    let n = knots.len() - degree - 1;
+    if degree == 0 {
+        return vec![0.0; n];
+    }
+
+    let lower = bspline_basis(knots, degree - 1, x, last_span);
+    let ratio = |den: f64| if den == 0.0 { 0.0 } else { degree as f64 / den };
+
+    (0..n)
+        .map(|i| {
+            ratio(knots[i + degree] - knots[i]) * lower[i]
+                - ratio(knots[i + degree + 1] - knots[i + 1]) * lower[i + 1]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "x" => [0.0f64, 0.5, 1.0, 1.5, 2.0],
+            "label" => ["a", "b", "c", "d", "e"]
+        ]
+        .unwrap()
+    }
+
+    fn basis_of(df: &DataFrame, row: usize) -> Vec<f64> {
+        df.get_columns()
+            .iter()
+            .filter(|col| col.name().starts_with("x_sp_"))
+            .map(|col| col.f64().unwrap().get(row).unwrap())
+            .collect()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let spline = SplineTransformer::default();
+        assert_eq!(spline.n_knots(), 5);
+        assert_eq!(spline.degree(), 3);
+        assert_eq!(spline.strategy, KnotStrategy::Uniform);
+        assert_eq!(spline.extrapolation, SplineExtrapolation::Constant);
+        assert!(spline.knots().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_knots() {
+        let df = df!["x" => [0.0f64, 1.0, 2.0, 3.0, 10.0]].unwrap();
+
+        let mut spline = SplineTransformer::new(3, 2);
+        spline.fit(&df).unwrap();
+        assert_eq!(
+            spline.knots().unwrap(),
+            &df!["x" => [0.0f64, 5.0, 10.0]].unwrap()
+        );
+
+        let mut spline = SplineTransformer::new(3, 2).with_strategy(KnotStrategy::Quantile);
+        spline.fit(&df).unwrap();
+        assert_eq!(
+            spline.knots().unwrap(),
+            &df!["x" => [0.0f64, 2.0, 10.0]].unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fit_duplicate_knots() {
+        let df = df!["x" => [1.0f64, 1.0, 1.0, 1.0, 2.0]].unwrap();
+        let mut spline = SplineTransformer::new(3, 2).with_strategy(KnotStrategy::Quantile);
+        assert!(matches!(
+            spline.fit(&df),
+            Err(TransformationError::InvalidParameterError(_))
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_linear_spline() {
+        let mut spline = SplineTransformer::new(3, 1);
+        let expanded_df = spline
+            .fit_transform(&create_df().select(["x"]).unwrap())
+            .unwrap();
+
+        // Degree 1 B-splines are the hat functions centered on the knots 0, 1 and 2.
+        let expected = df![
+            "x_sp_0" => [1.0f64, 0.5, 0.0, 0.0, 0.0],
+            "x_sp_1" => [0.0f64, 0.5, 1.0, 0.5, 0.0],
+            "x_sp_2" => [0.0f64, 0.0, 0.0, 0.5, 1.0]
+        ]
+        .unwrap();
+        assert_eq!(expanded_df, expected);
+    }
+
+    #[test]
+    fn test_transform_cubic_partition_of_unity() {
+        let mut spline = SplineTransformer::new(4, 3);
+        spline.fit(&create_df().select(["x"]).unwrap()).unwrap();
+        let expanded_df = spline.transform(&create_df()).unwrap();
+
+        assert_eq!(
+            spline.feature_names_out().unwrap(),
+            ["x_sp_0", "x_sp_1", "x_sp_2", "x_sp_3", "x_sp_4", "x_sp_5"]
+        );
+        assert_eq!(expanded_df.width(), 7);
+        assert!(expanded_df.column("label").is_ok());
+
+        for row in 0..5 {
+            let basis = basis_of(&expanded_df, row);
+            assert_abs_diff_eq!(basis.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+        }
+
+        // At the first knot, the cubic basis is (1/6, 2/3, 1/6) on the first three splines.
+        let first = basis_of(&expanded_df, 0);
+        assert_abs_diff_eq!(first[0], 1.0 / 6.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(first[1], 2.0 / 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(first[2], 1.0 / 6.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transform_extrapolation_constant() {
+        let mut spline = SplineTransformer::new(3, 1);
+        spline.fit(&create_df().select(["x"]).unwrap()).unwrap();
+
+        let df = df!["x" => [-1.0f64, 3.0]].unwrap();
+        let expanded_df = spline.transform(&df).unwrap();
+        assert_eq!(basis_of(&expanded_df, 0), [1.0, 0.0, 0.0]);
+        assert_eq!(basis_of(&expanded_df, 1), [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_transform_extrapolation_linear() {
+        let mut spline =
+            SplineTransformer::new(3, 1).with_extrapolation(SplineExtrapolation::Linear);
+        spline.fit(&create_df().select(["x"]).unwrap()).unwrap();
+
+        let df = df!["x" => [-1.0f64, 3.0]].unwrap();
+        let expanded_df = spline.transform(&df).unwrap();
+        assert_eq!(basis_of(&expanded_df, 0), [2.0, -1.0, 0.0]);
+        assert_eq!(basis_of(&expanded_df, 1), [0.0, -1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_transform_extrapolation_periodic() {
+        let mut spline =
+            SplineTransformer::new(3, 1).with_extrapolation(SplineExtrapolation::Periodic);
+        spline.fit(&create_df().select(["x"]).unwrap()).unwrap();
+
+        let df = df!["x" => [0.0f64, 0.5, 2.0, 2.5, -0.5]].unwrap();
+        let expanded_df = spline.transform(&df).unwrap();
+        assert_eq!(spline.feature_names_out().unwrap(), ["x_sp_0", "x_sp_1"]);
+
+        // The period is 2, so 2 wraps to 0, 2.5 to 0.5 and -0.5 to 1.5.
+        assert_eq!(basis_of(&expanded_df, 0), [1.0, 0.0]);
+        assert_eq!(basis_of(&expanded_df, 1), [0.5, 0.5]);
+        assert_eq!(basis_of(&expanded_df, 2), [1.0, 0.0]);
+        assert_eq!(basis_of(&expanded_df, 3), [0.5, 0.5]);
+        assert_eq!(basis_of(&expanded_df, 4), [0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_transform_periodic_cubic_is_continuous() {
+        let mut spline =
+            SplineTransformer::new(5, 3).with_extrapolation(SplineExtrapolation::Periodic);
+        spline.fit(&create_df().select(["x"]).unwrap()).unwrap();
+
+        let df = df!["x" => [0.0f64, 2.0 - 1e-9]].unwrap();
+        let expanded_df = spline.transform(&df).unwrap();
+        let (start, end) = (basis_of(&expanded_df, 0), basis_of(&expanded_df, 1));
+        for (a, b) in start.iter().zip(&end) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+        assert_abs_diff_eq!(start.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transform_extrapolation_error() {
+        let mut spline =
+            SplineTransformer::new(3, 1).with_extrapolation(SplineExtrapolation::Error);
+        spline.fit(&create_df().select(["x"]).unwrap()).unwrap();
+
+        let df = df!["x" => [1.0f64, 3.0]].unwrap();
+        assert!(matches!(
+            spline.transform(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::OutOfRangeError(ref cols, ..)
+            )) if cols == "x"
+        ));
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let spline = SplineTransformer::default();
+        assert!(matches!(
+            spline.transform(&create_df()),
+            Err(TransformationError::NotFittedError)
+        ));
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut spline = SplineTransformer::new(3, 1);
+        spline.fit(&create_df().select(["x"]).unwrap()).unwrap();
+
+        spline.reset();
+        assert!(spline.knots().is_none());
+    }
+}
@@ -0,0 +1,221 @@
+use polars::prelude::*;
+
+use super::error::*;
+use super::traits::InvertibleTransformer;
+
+/// Applies an invertible transformer, such as any scaler of `scaling::standardization` or a log
+/// `FunctionTransformer`, to a single target Series.
+///
+/// Series passed to `transform` and `inverse_transform`, such as model predictions, may carry
+/// any name: they are matched to the fitted target and keep their own name.
+pub struct TargetTransformer<T: InvertibleTransformer> {
+    transformer: T,
+    target: Option<String>,
+}
+
+impl<T: InvertibleTransformer + Default> Default for TargetTransformer<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: InvertibleTransformer> TargetTransformer<T> {
+    pub fn new(transformer: T) -> Self {
+        TargetTransformer {
+            transformer,
+            target: None,
+        }
+    }
+
+    pub fn transformer(&self) -> &T {
+        &self.transformer
+    }
+
+    /// The name of the fitted target.
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+
+    /// Learns the parameters of the transformer from the target.
+    pub fn fit(&mut self, y: &Series) -> Result<(), TransformationError> {
+        self.transformer.fit(&y.clone().into_frame())?;
+        self.target = Some(y.name().to_string());
+
+        Ok(())
+    }
+
+    /// Applies `apply` to the Series renamed to the fitted target, and restores its name.
+    fn apply(
+        &self,
+        y: &Series,
+        apply: impl Fn(&T, &DataFrame) -> Result<DataFrame, TransformationError>,
+    ) -> Result<Series, TransformationError> {
+        let target = self
+            .target
+            .as_deref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let df = y.clone().with_name(target.into()).into_frame();
+        let transformed_df = apply(&self.transformer, &df)?;
+
+        Ok(transformed_df
+            .column(target)?
+            .as_materialized_series()
+            .clone()
+            .with_name(y.name().clone()))
+    }
+
+    /// Transforms the target using the learned parameters.
+    pub fn transform(&self, y: &Series) -> Result<Series, TransformationError> {
+        self.apply(y, |transformer, df| transformer.transform(df))
+    }
+
+    /// Maps transformed values, such as predictions, back to the original target space.
+    pub fn inverse_transform(&self, y: &Series) -> Result<Series, TransformationError> {
+        self.apply(y, |transformer, df| transformer.inverse_transform(df))
+    }
+
+    /// Learns the parameters and returns the transformed target.
+    pub fn fit_transform(&mut self, y: &Series) -> Result<Series, TransformationError> {
+        self.fit(y)?;
+        self.transform(y)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.transformer.reset();
+        self.target = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::scaling::standardization::error::ScalingError;
+    use crate::scaling::standardization::max_abs::MaxAbsScaler;
+    use crate::scaling::standardization::min_max::MinMaxScaler;
+    use crate::scaling::standardization::power::PowerTransformer;
+    use crate::scaling::standardization::robust::RobustScaler;
+    use crate::scaling::standardization::z_score::ZScoreScaler;
+    use crate::transformation::function::*;
+
+    fn create_target() -> Series {
+        Series::new("price".into(), [10.0f64, 20.0, 30.0, 40.0, 50.0])
+    }
+
+    fn assert_series_close(left: &Series, right: &Series) {
+        assert_eq!(left.name(), right.name());
+        let left = left.f64().unwrap();
+        let right = right.f64().unwrap();
+        for (l, r) in left.into_no_null_iter().zip(right.into_no_null_iter()) {
+            assert_abs_diff_eq!(l, r, epsilon = 1e-9);
+        }
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let transformer = TargetTransformer::<ZScoreScaler>::default();
+        assert!(transformer.target().is_none());
+        assert!(transformer.transformer().mean().is_none());
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform_z_score() {
+        let mut transformer = TargetTransformer::new(ZScoreScaler::new());
+        let transformed = transformer.fit_transform(&create_target()).unwrap();
+
+        assert_eq!(transformer.target(), Some("price"));
+        let std = 250.0f64.sqrt();
+        let expected = Series::new(
+            "price".into(),
+            [-20.0 / std, -10.0 / std, 0.0, 10.0 / std, 20.0 / std],
+        );
+        assert_series_close(&transformed, &expected);
+    }
+
+    #[test]
+    fn test_transform_min_max() {
+        let mut transformer = TargetTransformer::new(MinMaxScaler::new());
+        let transformed = transformer.fit_transform(&create_target()).unwrap();
+        let expected = Series::new("price".into(), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_series_close(&transformed, &expected);
+
+        let restored = transformer.inverse_transform(&transformed).unwrap();
+        assert_series_close(&restored, &create_target());
+    }
+
+    #[test]
+    fn test_inverse_transform_scalers() {
+        fn assert_round_trip<T: InvertibleTransformer>(transformer: T) {
+            let mut transformer = TargetTransformer::new(transformer);
+            let transformed = transformer.fit_transform(&create_target()).unwrap();
+            let restored = transformer.inverse_transform(&transformed).unwrap();
+            assert_series_close(&restored, &create_target());
+        }
+
+        assert_round_trip(ZScoreScaler::new());
+        assert_round_trip(RobustScaler::new());
+        assert_round_trip(MaxAbsScaler::new());
+        assert_round_trip(PowerTransformer::new());
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let transformer = TargetTransformer::new(ZScoreScaler::new());
+        assert!(matches!(
+            transformer.transform(&create_target()),
+            Err(TransformationError::NotFittedError)
+        ));
+    }
+
+    // Test inverse_transform method
+    #[test]
+    fn test_inverse_transform_predictions() {
+        let mut transformer = TargetTransformer::new(ZScoreScaler::new());
+        transformer.fit(&create_target()).unwrap();
+
+        let predictions = Series::new("prediction".into(), [0.0f64, 1.0]);
+        let restored = transformer.inverse_transform(&predictions).unwrap();
+
+        let expected = Series::new("prediction".into(), [30.0, 30.0 + 250.0f64.sqrt()]);
+        assert_series_close(&restored, &expected);
+    }
+
+    #[test]
+    fn test_inverse_transform_log() {
+        let mut transformer =
+            TargetTransformer::new(FunctionTransformer::new(BuiltinFunction::Log));
+        let transformed = transformer.fit_transform(&create_target()).unwrap();
+
+        let restored = transformer.inverse_transform(&transformed).unwrap();
+        assert_series_close(&restored, &create_target());
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut transformer = TargetTransformer::new(ZScoreScaler::new());
+        let constant = Series::new("price".into(), [1.0f64, 1.0, 1.0]);
+        assert!(matches!(
+            transformer.fit(&constant),
+            Err(TransformationError::ScalingError(
+                ScalingError::ZeroDenominatorError(..)
+            ))
+        ));
+        assert!(transformer.target().is_none());
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut transformer = TargetTransformer::new(ZScoreScaler::new());
+        transformer.fit(&create_target()).unwrap();
+
+        transformer.reset();
+        assert!(transformer.target().is_none());
+        assert!(transformer.transformer().mean().is_none());
+    }
+}
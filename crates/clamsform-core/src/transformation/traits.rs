@@ -0,0 +1,23 @@
+use polars::prelude::DataFrame;
+
+use super::error::TransformationError;
+
+pub trait InvertibleTransformer {
+    /// Learns the parameters of the transformation from the dataframe
+    fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError>;
+
+    /// Transforms the dataframe using the learned parameters
+    fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError>;
+
+    /// Maps transformed values back to their original space
+    fn inverse_transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError>;
+
+    /// Learns the parameters and returns the transformed dataframe
+    fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    fn reset(&mut self);
+}
@@ -0,0 +1,396 @@
+use polars::prelude::*;
+
+use super::error::*;
+use crate::validation::error::*;
+
+/// How the clipping bounds of a column are learned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WinsorLimits {
+    /// The given lower and upper quantiles, between 0 and 1, such as 0.01 and 0.99.
+    Quantile { lower: f64, upper: f64 },
+    /// The mean plus or minus the given number of sample standard deviations.
+    StdDev(f64),
+}
+
+/// The learned clipping bounds of a fitted column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WinsorBounds {
+    pub column: String,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// The number of values of a column clipped to each bound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClippedCounts {
+    pub column: String,
+    pub below: u32,
+    pub above: u32,
+}
+
+/// Clips numeric columns to bounds learned at fit time, taming extreme outliers before
+/// their statistics are computed by a scaler.
+pub struct Winsorizer {
+    limits: WinsorLimits,
+    bounds: Option<Vec<WinsorBounds>>,
+}
+
+impl Default for Winsorizer {
+    fn default() -> Self {
+        Self::new(WinsorLimits::Quantile {
+            lower: 0.01,
+            upper: 0.99,
+        })
+    }
+}
+
+impl Winsorizer {
+    pub fn new(limits: WinsorLimits) -> Self {
+        Winsorizer {
+            limits,
+            bounds: None,
+        }
+    }
+
+    pub fn limits(&self) -> WinsorLimits {
+        self.limits
+    }
+
+    /// The bounds of every fitted column.
+    pub fn bounds(&self) -> Option<&[WinsorBounds]> {
+        self.bounds.as_deref()
+    }
+
+    fn bound_exprs(&self, name: &str) -> (Expr, Expr) {
+        match self.limits {
+            WinsorLimits::Quantile { lower, upper } => (
+                col(name).quantile(lit(lower), QuantileMethod::Linear),
+                col(name).quantile(lit(upper), QuantileMethod::Linear),
+            ),
+            WinsorLimits::StdDev(k) => (
+                col(name).mean() - lit(k) * col(name).std(1),
+                col(name).mean() + lit(k) * col(name).std(1),
+            ),
+        }
+    }
+
+    /// Learns the bounds of every column.
+    pub fn fit(&mut self, df: &DataFrame) -> Result<(), TransformationError> {
+        match self.limits {
+            WinsorLimits::Quantile { lower, upper }
+                if !(0.0..=1.0).contains(&lower)
+                    || !(0.0..=1.0).contains(&upper)
+                    || lower > upper =>
+            {
+                return Err(TransformationError::InvalidParameterError(format!(
+                    "quantile limits must satisfy 0 <= lower <= upper <= 1, got {lower} and {upper}"
+                )));
+            },
+            WinsorLimits::StdDev(k) if k.is_nan() || k <= 0.0 || k.is_infinite() => {
+                return Err(TransformationError::InvalidParameterError(format!(
+                    "the number of standard deviations must be positive and finite, got {k}"
+                )));
+            },
+            _ => {},
+        }
+
+        validate_dataframe(df)?;
+
+        let exprs: Vec<Expr> = df
+            .get_column_names()
+            .into_iter()
+            .flat_map(|name| {
+                let (lower, upper) = self.bound_exprs(name);
+                [lower.cast(DataType::Float64), upper.cast(DataType::Float64)]
+            })
+            .enumerate()
+            .map(|(i, expr)| expr.alias(format!("bound_{i}")))
+            .collect();
+
+        let bound_df = df.clone().lazy().select(exprs).collect()?;
+
+        let values = bound_df
+            .get_columns()
+            .iter()
+            .map(|bound| Ok(bound.f64()?.get(0).unwrap_or(f64::NAN)))
+            .collect::<Result<Vec<f64>, TransformationError>>()?;
+
+        let bounds = df
+            .get_column_names()
+            .into_iter()
+            .zip(values.chunks(2))
+            .map(|(name, bounds)| WinsorBounds {
+                column: name.to_string(),
+                lower: bounds[0],
+                upper: bounds[1],
+            })
+            .collect();
+
+        self.bounds = Some(bounds);
+
+        Ok(())
+    }
+
+    /// Clips the fitted columns to their bounds. Other columns are passed through.
+    pub fn transform(&self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        let bounds = self.validated_bounds(df)?;
+
+        let exprs: Vec<Expr> = bounds
+            .iter()
+            .map(|bounds| {
+                col(bounds.column.as_str())
+                    .clip(lit(bounds.lower), lit(bounds.upper))
+                    .alias(bounds.column.as_str())
+            })
+            .collect();
+
+        let clipped_df = df.clone().lazy().with_columns(exprs).collect()?;
+
+        Ok(clipped_df)
+    }
+
+    /// Counts the values of every fitted column that `transform` clips to each bound.
+    pub fn clipped_counts(
+        &self,
+        df: &DataFrame,
+    ) -> Result<Vec<ClippedCounts>, TransformationError> {
+        let bounds = self.validated_bounds(df)?;
+
+        let exprs: Vec<Expr> = bounds
+            .iter()
+            .flat_map(|bounds| {
+                let name = bounds.column.as_str();
+                [
+                    col(name).lt(lit(bounds.lower)),
+                    col(name).gt(lit(bounds.upper)),
+                ]
+            })
+            .enumerate()
+            .map(|(i, expr)| {
+                expr.sum()
+                    .cast(DataType::UInt32)
+                    .alias(format!("count_{i}"))
+            })
+            .collect();
+
+        let count_df = df.clone().lazy().select(exprs).collect()?;
+
+        let values = count_df
+            .get_columns()
+            .iter()
+            .map(|count| Ok(count.u32()?.get(0).unwrap_or(0)))
+            .collect::<Result<Vec<u32>, TransformationError>>()?;
+
+        let counts = bounds
+            .iter()
+            .zip(values.chunks(2))
+            .map(|(bounds, counts)| ClippedCounts {
+                column: bounds.column.clone(),
+                below: counts[0],
+                above: counts[1],
+            })
+            .collect();
+
+        Ok(counts)
+    }
+
+    /// Learns the bounds and returns the clipped DataFrame.
+    pub fn fit_transform(&mut self, df: &DataFrame) -> Result<DataFrame, TransformationError> {
+        self.fit(df)?;
+        self.transform(df)
+    }
+
+    /// Reset internal state
+    pub fn reset(&mut self) {
+        self.bounds = None;
+    }
+
+    fn validated_bounds(&self, df: &DataFrame) -> Result<&[WinsorBounds], TransformationError> {
+        let bounds = self
+            .bounds
+            .as_ref()
+            .ok_or(TransformationError::NotFittedError)?;
+
+        let fitted: Vec<&str> = bounds.iter().map(|bounds| bounds.column.as_str()).collect();
+        validate_fitted_columns(df, &fitted)?;
+
+        Ok(bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    fn create_df() -> DataFrame {
+        df![
+            "feature1" => [1.0f64, 2.0, 3.0, 4.0, 100.0],
+            "feature2" => [-50.0f64, 0.0, 1.0, 2.0, 3.0]
+        ]
+        .unwrap()
+    }
+
+    // Test constructor and default
+    #[test]
+    fn test_new_constructor() {
+        let winsorizer = Winsorizer::default();
+        assert_eq!(
+            winsorizer.limits(),
+            WinsorLimits::Quantile {
+                lower: 0.01,
+                upper: 0.99
+            }
+        );
+        assert!(winsorizer.bounds().is_none());
+    }
+
+    // Test fit method
+    #[test]
+    fn test_fit_quantile() {
+        let mut winsorizer = Winsorizer::new(WinsorLimits::Quantile {
+            lower: 0.25,
+            upper: 0.75,
+        });
+        winsorizer.fit(&create_df()).unwrap();
+
+        assert_eq!(
+            winsorizer.bounds().unwrap(),
+            [
+                WinsorBounds {
+                    column: "feature1".to_string(),
+                    lower: 2.0,
+                    upper: 4.0
+                },
+                WinsorBounds {
+                    column: "feature2".to_string(),
+                    lower: 0.0,
+                    upper: 2.0
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fit_std_dev() {
+        let df = df!["feature" => [1.0f64, 2.0, 3.0]].unwrap();
+        let mut winsorizer = Winsorizer::new(WinsorLimits::StdDev(2.0));
+        winsorizer.fit(&df).unwrap();
+
+        let bounds = &winsorizer.bounds().unwrap()[0];
+        assert_abs_diff_eq!(bounds.lower, 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(bounds.upper, 4.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut winsorizer = Winsorizer::new(WinsorLimits::Quantile {
+            lower: 0.9,
+            upper: 0.1,
+        });
+        assert!(matches!(
+            winsorizer.fit(&create_df()),
+            Err(TransformationError::InvalidParameterError(_))
+        ));
+
+        let mut winsorizer = Winsorizer::new(WinsorLimits::StdDev(-1.0));
+        assert!(matches!(
+            winsorizer.fit(&create_df()),
+            Err(TransformationError::InvalidParameterError(_))
+        ));
+
+        let df = df!["label" => ["a", "b"]].unwrap();
+        assert!(matches!(
+            Winsorizer::default().fit(&df),
+            Err(TransformationError::ValidationError(
+                ValidationError::NonNumericError(_)
+            ))
+        ));
+    }
+
+    // Test transform method
+    #[test]
+    fn test_transform() {
+        let mut winsorizer = Winsorizer::new(WinsorLimits::Quantile {
+            lower: 0.25,
+            upper: 0.75,
+        });
+        let clipped_df = winsorizer.fit_transform(&create_df()).unwrap();
+
+        let expected = df![
+            "feature1" => [2.0f64, 2.0, 3.0, 4.0, 4.0],
+            "feature2" => [0.0f64, 0.0, 1.0, 2.0, 2.0]
+        ]
+        .unwrap();
+        assert_eq!(clipped_df, expected);
+    }
+
+    #[test]
+    fn test_transform_passes_through_other_columns() {
+        let mut winsorizer = Winsorizer::new(WinsorLimits::Quantile {
+            lower: 0.0,
+            upper: 0.75,
+        });
+        winsorizer
+            .fit(&create_df().select(["feature1"]).unwrap())
+            .unwrap();
+
+        let df = df![
+            "feature1" => [10.0f64, 0.0],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        let expected = df![
+            "feature1" => [4.0f64, 1.0],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        assert_eq!(winsorizer.transform(&df).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_transform_not_fitted() {
+        let winsorizer = Winsorizer::default();
+        assert!(matches!(
+            winsorizer.transform(&create_df()),
+            Err(TransformationError::NotFittedError)
+        ));
+    }
+
+    // Test clipped_counts method
+    #[test]
+    fn test_clipped_counts() {
+        let mut winsorizer = Winsorizer::new(WinsorLimits::Quantile {
+            lower: 0.25,
+            upper: 0.75,
+        });
+        winsorizer.fit(&create_df()).unwrap();
+
+        assert_eq!(
+            winsorizer.clipped_counts(&create_df()).unwrap(),
+            [
+                ClippedCounts {
+                    column: "feature1".to_string(),
+                    below: 1,
+                    above: 1
+                },
+                ClippedCounts {
+                    column: "feature2".to_string(),
+                    below: 1,
+                    above: 1
+                }
+            ]
+        );
+    }
+
+    // Test reset method
+    #[test]
+    fn test_reset_method() {
+        let mut winsorizer = Winsorizer::default();
+        winsorizer.fit(&create_df()).unwrap();
+
+        winsorizer.reset();
+        assert!(winsorizer.bounds().is_none());
+    }
+}
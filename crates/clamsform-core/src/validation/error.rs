@@ -38,6 +38,77 @@ pub enum ValidationError {
     )]
     MissingValuesError(String),
 
+    #[error(
+        "The column(s) {0} contain a single distinct value. \
+        Constant features carry no information and produce zero denominators when scaled. \
+        Consider removing these features before applying normalization techniques."
+    )]
+    ConstantColumnError(String),
+
+    #[error(
+        "The column(s) {0} have a relative standard deviation below {1}. \
+        Near-constant features can cause numeric instability when scaled. \
+        Consider removing these features or applying a different normalization technique."
+    )]
+    NearConstantColumnError(String, f64),
+
+    #[error(
+        "The column(s) {0} contain fewer than {1} distinct values. \
+        Low-cardinality features may be better handled as categorical data."
+    )]
+    LowCardinalityError(String, usize),
+
+    #[error(
+        "The column(s) {0} contain values outside the range [{1}, {2}]. \
+        Handle out-of-range values before attempting to apply normalization techniques."
+    )]
+    OutOfRangeError(String, f64, f64),
+
+    #[error(
+        "The column(s) {0} contain values at or below {1}. \
+        The function is only defined for values greater than {1}."
+    )]
+    ValuesBelowBoundError(String, f64),
+
+    #[error(
+        "The column(s) {0} contain negative values. \
+        The function is only defined for non-negative values."
+    )]
+    NegativeValuesError(String),
+
+    #[error(
+        "The column(s) {0} contain zero values. \
+        The function is only defined for non-zero values."
+    )]
+    ZeroValuesError(String),
+
+    #[error("The column(s) {0} are required but missing from the DataFrame.")]
+    MissingColumnsError(String),
+
+    #[error("The column(s) {0} do not match the data types required by the schema.")]
+    DtypeMismatchError(String),
+
+    #[error("The column(s) {0} contain values outside their allowed categories.")]
+    DisallowedCategoriesError(String),
+
+    #[error(
+        "The column(s) {0} contain duplicate values. \
+        The schema requires these columns to be unique."
+    )]
+    DuplicateValuesError(String),
+
+    #[error(
+        "The {0} detector flagged {1} row(s) as outliers. \
+        Review these rows before fitting scalers, as they can distort the learned statistics."
+    )]
+    OutliersError(String, usize),
+
+    #[error("The {0} rule could not check the DataFrame: {1}")]
+    RuleCheckError(String, Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("The schema spec could not be loaded: {0}")]
+    SchemaSpecError(String),
+
     #[error(transparent)]
     PolarsError(#[from] PolarsError),
 }
@@ -60,6 +131,45 @@ pub fn validate_not_empty_df(df: &DataFrame) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Validates that every named column is present in the DataFrame.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check for the columns.
+/// * `columns` - The names of the required columns, such as those seen during fitting.
+///
+/// # Returns
+/// * `Ok(())` if every column is present.
+/// * `Err(ValidationError::MissingColumnsError)` with a list of absent columns, in the given order.
+pub fn validate_columns_present(df: &DataFrame, columns: &[&str]) -> Result<(), ValidationError> {
+    let missing_cols: Vec<&str> = columns
+        .iter()
+        .filter(|name| df.column(name).is_err())
+        .copied()
+        .collect();
+
+    if !missing_cols.is_empty() {
+        return Err(ValidationError::MissingColumnsError(
+            missing_cols.join(", "),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates that every fitted column is present in the DataFrame and holds valid numeric data.
+///
+/// # Arguments
+/// * `df` - The DataFrame to transform.
+/// * `columns` - The names of the fitted columns.
+///
+/// # Returns
+/// * `Ok(())` if every fitted column is present and passes `validate_dataframe`.
+/// * `Err(ValidationError)` with the first failed check, starting with `MissingColumnsError`.
+pub fn validate_fitted_columns(df: &DataFrame, columns: &[&str]) -> Result<(), ValidationError> {
+    validate_columns_present(df, columns)?;
+    validate_dataframe(&df.select(columns.iter().copied())?)
+}
+
 /// Validates that all columns in the DataFrame are of type `Float64` or `Float32`.
 ///
 /// # Arguments
@@ -85,6 +195,52 @@ pub fn validate_numeric_columns(df: &DataFrame) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// The number of missing, `NaN` and infinite values of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InvalidValueCounts {
+    pub null: usize,
+    pub nan: usize,
+    pub infinite: usize,
+}
+
+/// Counts the values of a column rejected by `validate_dataframe`.
+///
+/// # Arguments
+/// * `col` - The column to count invalid values in.
+///
+/// # Returns
+/// * `Ok(InvalidValueCounts)` with the null count, and the `NaN` and infinite counts of
+///   float columns. Other columns hold neither `NaN` nor infinite values.
+pub fn count_invalid_values(col: &Column) -> Result<InvalidValueCounts, ValidationError> {
+    let mut counts = InvalidValueCounts {
+        null: col.null_count(),
+        ..Default::default()
+    };
+
+    if col.dtype().is_float() {
+        counts.nan = col.is_nan()?.sum().unwrap_or(0) as usize;
+        counts.infinite = col.is_infinite()?.sum().unwrap_or(0) as usize;
+    }
+
+    Ok(counts)
+}
+
+/// Returns the names of the columns whose invalid value counts match `predicate`.
+fn columns_with_invalid_values(
+    df: &DataFrame,
+    predicate: impl Fn(&InvalidValueCounts) -> bool,
+) -> Result<Vec<String>, ValidationError> {
+    let mut matching_cols: Vec<String> = Vec::new();
+
+    for col in df.get_columns() {
+        if predicate(&count_invalid_values(col)?) {
+            matching_cols.push(col.name().to_string());
+        }
+    }
+
+    Ok(matching_cols)
+}
+
 /// Validates that no columns in the DataFrame contain `NaN` (Not a Number) values.
 ///
 /// # Arguments
@@ -94,12 +250,7 @@ pub fn validate_numeric_columns(df: &DataFrame) -> Result<(), ValidationError> {
 /// * `Ok(())` if no `NaN` values are found in float columns.
 /// * `Err(ValidationError::NanValuesError)` with a list of columns containing NaN values.
 pub fn validate_nan_values(df: &DataFrame) -> Result<(), ValidationError> {
-    let nan_cols: Vec<String> = df
-        .get_columns()
-        .iter()
-        .filter(|col| col.is_nan().unwrap().any())
-        .map(|col| col.name().to_string())
-        .collect();
+    let nan_cols = columns_with_invalid_values(df, |counts| counts.nan > 0)?;
 
     if !nan_cols.is_empty() {
         Err(ValidationError::NanValuesError(nan_cols.join(", ")))?;
@@ -117,12 +268,7 @@ pub fn validate_nan_values(df: &DataFrame) -> Result<(), ValidationError> {
 /// * `Ok(())` if no infinite values are found in float columns.
 /// * `Err(ValidationError::InfiniteValuesError)` with a list of columns containing infinite values.
 pub fn validate_infinite_values(df: &DataFrame) -> Result<(), ValidationError> {
-    let inf_cols: Vec<String> = df
-        .get_columns()
-        .iter()
-        .filter(|col| col.is_infinite().unwrap().any())
-        .map(|col| col.name().to_string())
-        .collect();
+    let inf_cols = columns_with_invalid_values(df, |counts| counts.infinite > 0)?;
 
     if !inf_cols.is_empty() {
         Err(ValidationError::InfiniteValuesError(inf_cols.join(", ")))?;
@@ -140,15 +286,234 @@ pub fn validate_infinite_values(df: &DataFrame) -> Result<(), ValidationError> {
 /// * `Ok(())` if no missing values are found.
 /// * `Err(ValidationError::MissingValuesError)` with a list of columns containing missing (null) values.
 pub fn validate_missing_values(df: &DataFrame) -> Result<(), ValidationError> {
-    let missing_cols: Vec<String> = df
+    let missing_cols = columns_with_invalid_values(df, |counts| counts.null > 0)?;
+
+    if !missing_cols.is_empty() {
+        Err(ValidationError::MissingValuesError(missing_cols.join(", ")))?;
+    }
+
+    Ok(())
+}
+
+/// Validates that no columns in the DataFrame hold a single distinct (non-null) value.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check for constant columns.
+///
+/// # Returns
+/// * `Ok(())` if every column has at least two distinct values.
+/// * `Err(ValidationError::ConstantColumnError)` with a list of constant columns.
+pub fn validate_constant_columns(df: &DataFrame) -> Result<(), ValidationError> {
+    let mut constant_cols: Vec<String> = Vec::new();
+
+    for col in df.get_columns() {
+        if col.as_materialized_series().drop_nulls().n_unique()? <= 1 {
+            constant_cols.push(col.name().to_string());
+        }
+    }
+
+    if !constant_cols.is_empty() {
+        Err(ValidationError::ConstantColumnError(
+            constant_cols.join(", "),
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Validates that no numeric columns in the DataFrame have a low relative variance.
+///
+/// The relative variance is measured as the coefficient of variation, `std / |mean|`.
+/// A column is flagged when its standard deviation is at most `threshold` times the
+/// magnitude of its mean. Non-numeric columns are ignored.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check for near-constant columns.
+/// * `threshold` - The coefficient of variation at or below which a column is flagged.
+///
+/// # Returns
+/// * `Ok(())` if every numeric column varies enough relative to its mean.
+/// * `Err(ValidationError::NearConstantColumnError)` with a list of near-constant columns.
+pub fn validate_near_constant_columns(
+    df: &DataFrame,
+    threshold: f64,
+) -> Result<(), ValidationError> {
+    let near_constant_cols: Vec<String> = df
         .get_columns()
         .iter()
-        .filter(|col| col.is_null().any())
+        .filter(|col| col.dtype().is_primitive_numeric())
+        .filter(|col| {
+            let series = col.as_materialized_series();
+            match (series.mean(), series.std(1)) {
+                (Some(mean), Some(std)) => std <= threshold * mean.abs(),
+                _ => false,
+            }
+        })
         .map(|col| col.name().to_string())
         .collect();
 
-    if !missing_cols.is_empty() {
-        Err(ValidationError::MissingValuesError(missing_cols.join(", ")))?;
+    if !near_constant_cols.is_empty() {
+        Err(ValidationError::NearConstantColumnError(
+            near_constant_cols.join(", "),
+            threshold,
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Validates that every column in the DataFrame has at least `min_unique` distinct (non-null) values.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check for low-cardinality columns.
+/// * `min_unique` - The minimum number of distinct values a column must contain.
+///
+/// # Returns
+/// * `Ok(())` if every column has at least `min_unique` distinct values.
+/// * `Err(ValidationError::LowCardinalityError)` with a list of low-cardinality columns.
+pub fn validate_low_cardinality(df: &DataFrame, min_unique: usize) -> Result<(), ValidationError> {
+    let mut low_cardinality_cols: Vec<String> = Vec::new();
+
+    for col in df.get_columns() {
+        if col.as_materialized_series().drop_nulls().n_unique()? < min_unique {
+            low_cardinality_cols.push(col.name().to_string());
+        }
+    }
+
+    if !low_cardinality_cols.is_empty() {
+        Err(ValidationError::LowCardinalityError(
+            low_cardinality_cols.join(", "),
+            min_unique,
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Validates that all values in the numeric columns of the DataFrame lie within `[min, max]`.
+///
+/// Non-numeric columns and missing values are ignored.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check for out-of-range values.
+/// * `min` - The inclusive lower bound.
+/// * `max` - The inclusive upper bound.
+///
+/// # Returns
+/// * `Ok(())` if every value lies within the bounds.
+/// * `Err(ValidationError::OutOfRangeError)` with a list of columns containing out-of-range values.
+pub fn validate_value_range(df: &DataFrame, min: f64, max: f64) -> Result<(), ValidationError> {
+    let mut out_of_range_cols: Vec<String> = Vec::new();
+
+    for col in df
+        .get_columns()
+        .iter()
+        .filter(|col| col.dtype().is_primitive_numeric())
+    {
+        let series = col.as_materialized_series();
+        let below = series.min::<f64>()?.is_some_and(|val| val < min);
+        let above = series.max::<f64>()?.is_some_and(|val| val > max);
+
+        if below || above {
+            out_of_range_cols.push(col.name().to_string());
+        }
+    }
+
+    if !out_of_range_cols.is_empty() {
+        Err(ValidationError::OutOfRangeError(
+            out_of_range_cols.join(", "),
+            min,
+            max,
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Returns the numeric columns of the DataFrame holding a value that matches `predicate`.
+///
+/// Non-numeric columns and missing values are ignored.
+fn columns_with_values(
+    df: &DataFrame,
+    predicate: impl Fn(f64) -> bool,
+) -> Result<Vec<String>, ValidationError> {
+    let mut matching_cols: Vec<String> = Vec::new();
+
+    for col in df
+        .get_columns()
+        .iter()
+        .filter(|col| col.dtype().is_primitive_numeric())
+    {
+        let series = col.as_materialized_series().cast(&DataType::Float64)?;
+        if series.f64()?.into_iter().flatten().any(&predicate) {
+            matching_cols.push(col.name().to_string());
+        }
+    }
+
+    Ok(matching_cols)
+}
+
+/// Validates that all values in the numeric columns of the DataFrame are greater than `bound`.
+///
+/// Non-numeric columns and missing values are ignored.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check, such as before a logarithm.
+/// * `bound` - The exclusive lower bound, such as 0 for `log` or -1 for `log1p`.
+///
+/// # Returns
+/// * `Ok(())` if every value is greater than `bound`.
+/// * `Err(ValidationError::ValuesBelowBoundError)` with a list of columns containing values at or below `bound`.
+pub fn validate_values_above(df: &DataFrame, bound: f64) -> Result<(), ValidationError> {
+    let below_bound_cols = columns_with_values(df, |val| val <= bound)?;
+
+    if !below_bound_cols.is_empty() {
+        Err(ValidationError::ValuesBelowBoundError(
+            below_bound_cols.join(", "),
+            bound,
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Validates that no numeric column in the DataFrame holds a negative value.
+///
+/// Non-numeric columns and missing values are ignored.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check, such as before a square root.
+///
+/// # Returns
+/// * `Ok(())` if every value is greater than or equal to 0.
+/// * `Err(ValidationError::NegativeValuesError)` with a list of columns containing negative values.
+pub fn validate_non_negative_values(df: &DataFrame) -> Result<(), ValidationError> {
+    let negative_cols = columns_with_values(df, |val| val < 0.0)?;
+
+    if !negative_cols.is_empty() {
+        Err(ValidationError::NegativeValuesError(
+            negative_cols.join(", "),
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Validates that no numeric column in the DataFrame holds a zero value.
+///
+/// Non-numeric columns and missing values are ignored.
+///
+/// # Arguments
+/// * `df` - The DataFrame to check, such as before a reciprocal.
+///
+/// # Returns
+/// * `Ok(())` if every value is different from 0.
+/// * `Err(ValidationError::ZeroValuesError)` with a list of columns containing zero values.
+pub fn validate_non_zero_values(df: &DataFrame) -> Result<(), ValidationError> {
+    let zero_cols = columns_with_values(df, |val| val == 0.0)?;
+
+    if !zero_cols.is_empty() {
+        Err(ValidationError::ZeroValuesError(zero_cols.join(", ")))?;
     }
 
     Ok(())
@@ -252,6 +617,45 @@ mod tests {
         assert!(validate_not_empty_df(&valid_df).is_ok());
     }
 
+    #[test]
+    fn test_validate_columns_present() {
+        let valid_df = create_valid_df();
+        let result = validate_columns_present(&valid_df, &["col3", "col1", "absent"]);
+        assert!(matches!(
+            result,
+            Err(ValidationError::MissingColumnsError(ref cols)) if cols == "col3, absent"
+        ));
+
+        assert!(validate_columns_present(&valid_df, &["col2", "col1"]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_fitted_columns() {
+        let invalid_df = create_invalid_df();
+        assert!(matches!(
+            validate_fitted_columns(&invalid_df, &["float_valid", "absent"]),
+            Err(ValidationError::MissingColumnsError(ref cols)) if cols == "absent"
+        ));
+        assert!(matches!(
+            validate_fitted_columns(&invalid_df, &["float_valid", "float_nan"]),
+            Err(ValidationError::NanValuesError(_))
+        ));
+
+        assert!(validate_fitted_columns(&invalid_df, &["float_valid"]).is_ok());
+    }
+
+    #[test]
+    fn test_count_invalid_values() {
+        let invalid_df = create_invalid_df();
+        let counts = |name: &str| count_invalid_values(invalid_df.column(name).unwrap()).unwrap();
+
+        assert_eq!(counts("float_valid"), InvalidValueCounts::default());
+        assert_eq!(counts("float_nan").nan, 1);
+        assert_eq!(counts("float_inf").infinite, 1);
+        assert_eq!(counts("nulls").null, 1);
+        assert_eq!(counts("int_col"), InvalidValueCounts::default());
+    }
+
     #[test]
     fn test_validate_numeric_columns() {
         let invalid_df = create_invalid_df();
@@ -298,6 +702,115 @@ mod tests {
         assert!(validate_missing_values(&valid_df).is_ok());
     }
 
+    #[test]
+    fn test_validate_constant_columns() {
+        let invalid_df = df![
+            "constant" => [2.0f64, 2.0, 2.0],
+            "varying" => [1.0f64, 2.0, 3.0]
+        ]
+        .unwrap();
+        let result = validate_constant_columns(&invalid_df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::ConstantColumnError(ref cols)) if cols == "constant"
+        ));
+
+        let valid_df = create_valid_df();
+        assert!(validate_constant_columns(&valid_df).is_ok());
+    }
+
+    #[test]
+    fn test_validate_near_constant_columns() {
+        let invalid_df = df![
+            "near_constant" => [1000.0f64, 1000.001, 999.999],
+            "varying" => [1.0f64, 2.0, 3.0]
+        ]
+        .unwrap();
+        let result = validate_near_constant_columns(&invalid_df, 1e-4);
+        assert!(matches!(
+            result,
+            Err(ValidationError::NearConstantColumnError(ref cols, _)) if cols == "near_constant"
+        ));
+
+        let valid_df = create_valid_df();
+        assert!(validate_near_constant_columns(&valid_df, 1e-4).is_ok());
+    }
+
+    #[test]
+    fn test_validate_low_cardinality() {
+        let invalid_df = df![
+            "binary" => [0.0f64, 1.0, 0.0, 1.0],
+            "varying" => [1.0f64, 2.0, 3.0, 4.0]
+        ]
+        .unwrap();
+        let result = validate_low_cardinality(&invalid_df, 3);
+        assert!(matches!(
+            result,
+            Err(ValidationError::LowCardinalityError(ref cols, 3)) if cols == "binary"
+        ));
+
+        let valid_df = create_valid_df();
+        assert!(validate_low_cardinality(&valid_df, 3).is_ok());
+    }
+
+    #[test]
+    fn test_validate_value_range() {
+        let valid_df = create_valid_df();
+        let result = validate_value_range(&valid_df, 0.0, 5.0);
+        assert!(matches!(
+            result,
+            Err(ValidationError::OutOfRangeError(ref cols, ..)) if cols == "col2"
+        ));
+
+        assert!(validate_value_range(&valid_df, 0.0, 6.0).is_ok());
+    }
+
+    #[test]
+    fn test_validate_values_above() {
+        let valid_df = create_valid_df();
+        let result = validate_values_above(&valid_df, 1.0);
+        assert!(matches!(
+            result,
+            Err(ValidationError::ValuesBelowBoundError(ref cols, bound))
+                if cols == "col1" && bound == 1.0
+        ));
+
+        assert!(validate_values_above(&valid_df, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_validate_non_negative_values() {
+        let df = df![
+            "col1" => [0.0f64, 1.0],
+            "col2" => [Some(-1.0f64), None],
+            "label" => ["a", "b"]
+        ]
+        .unwrap();
+        let result = validate_non_negative_values(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::NegativeValuesError(ref cols)) if cols == "col2"
+        ));
+
+        assert!(validate_non_negative_values(&create_valid_df()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_non_zero_values() {
+        let df = df![
+            "col1" => [0.0f64, 1.0],
+            "col2" => [-1.0f64, 2.0]
+        ]
+        .unwrap();
+        let result = validate_non_zero_values(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::ZeroValuesError(ref cols)) if cols == "col1"
+        ));
+
+        assert!(validate_non_zero_values(&create_valid_df()).is_ok());
+    }
+
     #[test]
     fn test_validate_dataframe() {
         let invalid_df = create_invalid_df();
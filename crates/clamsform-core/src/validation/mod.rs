@@ -1 +1,3 @@
 pub mod error;
+pub mod rules;
+pub mod schema;
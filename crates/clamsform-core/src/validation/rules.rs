@@ -0,0 +1,116 @@
+use polars::prelude::*;
+
+use super::error::*;
+
+/// How the failure of a rule is reported by `run_rules`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The failure is collected as a warning and the remaining rules still run.
+    Warn,
+    /// The failure is returned as an error.
+    Error,
+}
+
+pub trait ValidationRule {
+    /// A short name identifying the rule in warnings
+    fn name(&self) -> &str;
+
+    /// How a failure of the rule is reported
+    fn severity(&self) -> Severity;
+
+    /// Checks the dataframe, returning the failure if the rule does not hold
+    fn check(&self, df: &DataFrame) -> Result<(), ValidationError>;
+}
+
+/// The failure of a warn-level rule.
+#[derive(Debug)]
+pub struct RuleWarning {
+    pub rule: String,
+    pub error: ValidationError,
+}
+
+/// Runs every rule against a DataFrame, in order.
+///
+/// # Arguments
+/// * `df` - The DataFrame to validate.
+/// * `rules` - The rules to check.
+///
+/// # Returns
+/// * `Ok(warnings)` with the failures of the warn-level rules, if every error-level rule holds.
+/// * `Err(ValidationError)` with the first failure of an error-level rule. Polars errors are
+///   returned whatever the severity of the rule, as they do not describe the data.
+pub fn run_rules(
+    df: &DataFrame,
+    rules: &[&dyn ValidationRule],
+) -> Result<Vec<RuleWarning>, ValidationError> {
+    let mut warnings: Vec<RuleWarning> = Vec::new();
+
+    for rule in rules {
+        match (rule.check(df), rule.severity()) {
+            (Ok(()), _) => {},
+            (Err(ValidationError::PolarsError(e)), _) => return Err(e.into()),
+            (Err(error), Severity::Warn) => warnings.push(RuleWarning {
+                rule: rule.name().to_string(),
+                error,
+            }),
+            (Err(error), Severity::Error) => return Err(error),
+        }
+    }
+
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ConstantRule(Severity);
+
+    impl ValidationRule for ConstantRule {
+        fn name(&self) -> &str {
+            "constant"
+        }
+
+        fn severity(&self) -> Severity {
+            self.0
+        }
+
+        fn check(&self, df: &DataFrame) -> Result<(), ValidationError> {
+            validate_constant_columns(df)
+        }
+    }
+
+    fn create_df() -> DataFrame {
+        df![
+            "col1" => [1.0f64, 1.0, 1.0],
+            "col2" => [1.0f64, 2.0, 3.0]
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_run_rules_warn() {
+        let warn = ConstantRule(Severity::Warn);
+        let warnings = run_rules(&create_df(), &[&warn, &warn]).unwrap();
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].rule, "constant");
+        assert!(matches!(
+            warnings[0].error,
+            ValidationError::ConstantColumnError(ref cols) if cols == "col1"
+        ));
+    }
+
+    #[test]
+    fn test_run_rules_error() {
+        let warn = ConstantRule(Severity::Warn);
+        let error = ConstantRule(Severity::Error);
+        assert!(matches!(
+            run_rules(&create_df(), &[&warn, &error]),
+            Err(ValidationError::ConstantColumnError(_))
+        ));
+
+        let valid_df = create_df().select(["col2"]).unwrap();
+        assert!(run_rules(&valid_df, &[&warn, &error]).unwrap().is_empty());
+    }
+}
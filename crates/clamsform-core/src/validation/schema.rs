@@ -0,0 +1,439 @@
+use std::fs;
+use std::path::Path;
+
+use polars::prelude::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::error::*;
+
+/// The maximum number of distinct values for which `SchemaSpec::infer` records allowed categories.
+const INFER_MAX_CATEGORIES: usize = 20;
+
+/// A declarative data contract describing the columns a DataFrame must contain.
+///
+/// Specs can be loaded from YAML or TOML files, for example:
+///
+/// ```yaml
+/// columns:
+///   - name: age
+///     dtype: f64
+///     nullable: false
+///     min: 0.0
+///     max: 120.0
+///   - name: segment
+///     dtype: str
+///     allowed: [retail, business]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaSpec {
+    pub columns: Vec<ColumnSpec>,
+}
+
+/// The contract for a single column.
+///
+/// `dtype` uses the polars display name of the data type, e.g. `f64`, `i64`, `str` or `bool`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnSpec {
+    pub name: String,
+    pub dtype: String,
+    #[serde(default = "default_nullable")]
+    pub nullable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<Vec<String>>,
+    #[serde(default)]
+    pub unique: bool,
+}
+
+fn default_nullable() -> bool {
+    true
+}
+
+impl SchemaSpec {
+    /// Parses a spec from a YAML string.
+    pub fn from_yaml_str(yaml: &str) -> Result<Self, ValidationError> {
+        serde_yaml::from_str(yaml).map_err(|e| ValidationError::SchemaSpecError(e.to_string()))
+    }
+
+    /// Parses a spec from a TOML string.
+    pub fn from_toml_str(toml: &str) -> Result<Self, ValidationError> {
+        toml::from_str(toml).map_err(|e| ValidationError::SchemaSpecError(e.to_string()))
+    }
+
+    /// Loads a spec from a `.yaml`, `.yml` or `.toml` file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ValidationError> {
+        let path = path.as_ref();
+        let from_str = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => Self::from_yaml_str,
+            Some("toml") => Self::from_toml_str,
+            _ => {
+                return Err(ValidationError::SchemaSpecError(format!(
+                    "{}: unsupported file extension, expected .yaml, .yml or .toml",
+                    path.display()
+                )))
+            },
+        };
+
+        let contents = fs::read_to_string(path)
+            .map_err(|e| ValidationError::SchemaSpecError(format!("{}: {e}", path.display())))?;
+        from_str(&contents)
+    }
+
+    /// Serializes the spec to a YAML string.
+    pub fn to_yaml_string(&self) -> Result<String, ValidationError> {
+        serde_yaml::to_string(self).map_err(|e| ValidationError::SchemaSpecError(e.to_string()))
+    }
+
+    /// Serializes the spec to a TOML string.
+    pub fn to_toml_string(&self) -> Result<String, ValidationError> {
+        toml::to_string(self).map_err(|e| ValidationError::SchemaSpecError(e.to_string()))
+    }
+
+    /// Infers a starting spec from a DataFrame.
+    ///
+    /// Every column is recorded with its data type and nullability. Numeric columns record
+    /// their observed range, columns whose values are all distinct are marked unique, and
+    /// string, categorical and boolean columns with at most 20 distinct values record them
+    /// as the allowed categories.
+    pub fn infer(df: &DataFrame) -> Result<Self, ValidationError> {
+        let mut columns = Vec::with_capacity(df.width());
+
+        for col in df.get_columns() {
+            let series = col.as_materialized_series();
+            let dtype = series.dtype();
+            let non_null = series.drop_nulls();
+
+            let (min, max) = if dtype.is_primitive_numeric() {
+                (series.min::<f64>()?, series.max::<f64>()?)
+            } else {
+                (None, None)
+            };
+
+            let allowed = if dtype.is_string() || dtype.is_categorical() || dtype.is_bool() {
+                let categories = distinct_strings(&non_null)?;
+                (categories.len() <= INFER_MAX_CATEGORIES).then_some(categories)
+            } else {
+                None
+            };
+
+            columns.push(ColumnSpec {
+                name: series.name().to_string(),
+                dtype: dtype.to_string(),
+                nullable: series.null_count() > 0,
+                min,
+                max,
+                allowed,
+                unique: !non_null.is_empty() && non_null.n_unique()? == non_null.len(),
+            });
+        }
+
+        Ok(SchemaSpec { columns })
+    }
+
+    /// Validates a DataFrame against the spec.
+    ///
+    /// The checks run in the following order, returning the first failure:
+    /// - `MissingColumnsError` if any spec column is absent from the DataFrame.
+    /// - `DtypeMismatchError` if any column has a different data type than the spec.
+    /// - `MissingValuesError` if any non-nullable column contains null values.
+    /// - `OutOfRangeError` if any column has values outside its `min`/`max` bounds.
+    /// - `DisallowedCategoriesError` if any column has values outside its allowed categories.
+    /// - `DuplicateValuesError` if any unique column contains duplicate values.
+    pub fn validate(&self, df: &DataFrame) -> Result<(), ValidationError> {
+        let names: Vec<&str> = self.columns.iter().map(|spec| spec.name.as_str()).collect();
+        validate_columns_present(df, &names)?;
+
+        let dtype_mismatches: Vec<String> = self
+            .columns
+            .iter()
+            .filter_map(|spec| {
+                let found = df.column(&spec.name).ok()?.dtype().to_string();
+                (found != spec.dtype)
+                    .then(|| format!("{} (expected {}, found {found})", spec.name, spec.dtype))
+            })
+            .collect();
+
+        if !dtype_mismatches.is_empty() {
+            Err(ValidationError::DtypeMismatchError(
+                dtype_mismatches.join(", "),
+            ))?;
+        }
+
+        let non_nullable: Vec<&str> = self
+            .columns
+            .iter()
+            .filter(|spec| !spec.nullable)
+            .map(|spec| spec.name.as_str())
+            .collect();
+        validate_missing_values(&df.select(non_nullable)?)?;
+
+        for spec in self
+            .columns
+            .iter()
+            .filter(|spec| spec.min.is_some() || spec.max.is_some())
+        {
+            validate_value_range(
+                &df.select([spec.name.as_str()])?,
+                spec.min.unwrap_or(f64::NEG_INFINITY),
+                spec.max.unwrap_or(f64::INFINITY),
+            )?;
+        }
+
+        let mut disallowed_cols: Vec<String> = Vec::new();
+        for spec in &self.columns {
+            if let Some(allowed) = &spec.allowed {
+                let non_null = df.column(&spec.name)?.as_materialized_series().drop_nulls();
+                if distinct_strings(&non_null)?
+                    .iter()
+                    .any(|value| !allowed.contains(value))
+                {
+                    disallowed_cols.push(spec.name.clone());
+                }
+            }
+        }
+
+        if !disallowed_cols.is_empty() {
+            Err(ValidationError::DisallowedCategoriesError(
+                disallowed_cols.join(", "),
+            ))?;
+        }
+
+        let mut duplicate_cols: Vec<String> = Vec::new();
+        for spec in self.columns.iter().filter(|spec| spec.unique) {
+            let non_null = df.column(&spec.name)?.as_materialized_series().drop_nulls();
+            if non_null.n_unique()? != non_null.len() {
+                duplicate_cols.push(spec.name.clone());
+            }
+        }
+
+        if !duplicate_cols.is_empty() {
+            Err(ValidationError::DuplicateValuesError(
+                duplicate_cols.join(", "),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the sorted distinct values of a series, rendered as strings.
+fn distinct_strings(series: &Series) -> Result<Vec<String>, ValidationError> {
+    let unique = series.unique()?.cast(&DataType::String)?;
+    let mut values: Vec<String> = unique
+        .str()?
+        .into_iter()
+        .flatten()
+        .map(|value| value.to_string())
+        .collect();
+    values.sort();
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML_SPEC: &str = "
+columns:
+  - name: age
+    dtype: f64
+    nullable: false
+    min: 0.0
+    max: 120.0
+  - name: segment
+    dtype: str
+    allowed: [retail, business]
+  - name: id
+    dtype: i64
+    unique: true
+";
+
+    const TOML_SPEC: &str = r#"
+[[columns]]
+name = "age"
+dtype = "f64"
+nullable = false
+min = 0.0
+max = 120.0
+
+[[columns]]
+name = "segment"
+dtype = "str"
+allowed = ["retail", "business"]
+
+[[columns]]
+name = "id"
+dtype = "i64"
+unique = true
+"#;
+
+    fn create_valid_df() -> DataFrame {
+        df![
+            "age" => [25.0f64, 40.0, 67.0],
+            "segment" => [Some("retail"), None, Some("business")],
+            "id" => [1i64, 2, 3]
+        ]
+        .unwrap()
+    }
+
+    // Test spec loading
+    #[test]
+    fn test_from_yaml_and_toml_str() {
+        let yaml_spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        let toml_spec = SchemaSpec::from_toml_str(TOML_SPEC).unwrap();
+
+        assert_eq!(yaml_spec, toml_spec);
+        assert_eq!(yaml_spec.columns.len(), 3);
+        assert!(yaml_spec.columns[1].nullable);
+        assert!(!yaml_spec.columns[1].unique);
+    }
+
+    #[test]
+    fn test_from_invalid_str() {
+        let result = SchemaSpec::from_yaml_str("columns: 3");
+        assert!(matches!(result, Err(ValidationError::SchemaSpecError(_))));
+    }
+
+    #[test]
+    fn test_from_path_unsupported_extension() {
+        // The file exists, so the error comes from the extension check and not the read.
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/lib.rs");
+        let result = SchemaSpec::from_path(path);
+        assert!(matches!(
+            result,
+            Err(ValidationError::SchemaSpecError(ref message))
+                if message.contains("unsupported file extension")
+        ));
+    }
+
+    // Test validation
+    #[test]
+    fn test_validate_valid_df() {
+        let spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        assert!(spec.validate(&create_valid_df()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_missing_columns() {
+        let spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        let df = create_valid_df().drop("id").unwrap();
+
+        let result = spec.validate(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::MissingColumnsError(ref cols)) if cols == "id"
+        ));
+    }
+
+    #[test]
+    fn test_validate_dtype_mismatch() {
+        let spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        let mut df = create_valid_df();
+        df.with_column(Series::new("id".into(), [1.0f64, 2.0, 3.0]))
+            .unwrap();
+
+        let result = spec.validate(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::DtypeMismatchError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_nullability() {
+        let spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        let mut df = create_valid_df();
+        df.with_column(Series::new("age".into(), [Some(25.0f64), None, Some(67.0)]))
+            .unwrap();
+
+        let result = spec.validate(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::MissingValuesError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_value_range() {
+        let spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        let mut df = create_valid_df();
+        df.with_column(Series::new("age".into(), [25.0f64, 140.0, 67.0]))
+            .unwrap();
+
+        let result = spec.validate(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::OutOfRangeError(ref cols, ..)) if cols == "age"
+        ));
+    }
+
+    #[test]
+    fn test_validate_disallowed_categories() {
+        let spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        let mut df = create_valid_df();
+        df.with_column(Series::new(
+            "segment".into(),
+            ["retail", "government", "business"],
+        ))
+        .unwrap();
+
+        let result = spec.validate(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::DisallowedCategoriesError(ref cols)) if cols == "segment"
+        ));
+    }
+
+    #[test]
+    fn test_validate_duplicate_values() {
+        let spec = SchemaSpec::from_yaml_str(YAML_SPEC).unwrap();
+        let mut df = create_valid_df();
+        df.with_column(Series::new("id".into(), [1i64, 1, 3]))
+            .unwrap();
+
+        let result = spec.validate(&df);
+        assert!(matches!(
+            result,
+            Err(ValidationError::DuplicateValuesError(ref cols)) if cols == "id"
+        ));
+    }
+
+    // Test spec inference
+    #[test]
+    fn test_infer() {
+        let df = create_valid_df();
+        let spec = SchemaSpec::infer(&df).unwrap();
+
+        assert_eq!(spec.columns[0].dtype, "f64");
+        assert!(!spec.columns[0].nullable);
+        assert_eq!(spec.columns[0].min, Some(25.0));
+        assert_eq!(spec.columns[0].max, Some(67.0));
+
+        assert!(spec.columns[1].nullable);
+        assert_eq!(
+            spec.columns[1].allowed,
+            Some(vec!["business".to_string(), "retail".to_string()])
+        );
+
+        assert!(spec.columns[2].unique);
+        assert!(spec.validate(&df).is_ok());
+    }
+
+    #[test]
+    fn test_infer_round_trip() {
+        let spec = SchemaSpec::infer(&create_valid_df()).unwrap();
+
+        let from_yaml = SchemaSpec::from_yaml_str(&spec.to_yaml_string().unwrap()).unwrap();
+        let from_toml = SchemaSpec::from_toml_str(&spec.to_toml_string().unwrap()).unwrap();
+
+        assert_eq!(from_yaml, spec);
+        assert_eq!(from_toml, spec);
+    }
+}